@@ -0,0 +1,234 @@
+//! Algorithms: move sequences with their group operations.
+//!
+//! Notation is that of `Move::moves_from_notation`, plus `[A: B]` for conjugate `A B A'`
+//! and `[A, B]` for commutator `A B A' B'` (nestable). Printing expands them to plain moves.
+
+use std::{fmt, str::FromStr};
+
+use crate::{symmetry::symmetry_by_centers, Move, RubiksCube};
+
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mirror {
+	/// swaps L and R.
+	LeftRight,
+	/// swaps U and D.
+	TopBottom,
+}
+impl Mirror {
+	/// index into `SYMMETRIES`.
+	fn symmetry(self) -> usize {
+		match self {
+			Mirror::LeftRight => symmetry_by_centers([4, 28, 25, 22, 31, 49]),
+			Mirror::TopBottom => symmetry_by_centers([49, 22, 25, 28, 31, 4]),
+		}
+	}
+}
+
+/// Whole cube quarter rotation, clockwise as seen from the named face
+/// (`x`, `y` and `z` of common notation, which here are moves of two opposite faces).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+	Right,
+	Top,
+	Front,
+}
+impl Rotation {
+	/// index into `SYMMETRIES`.
+	fn symmetry(self) -> usize {
+		match self {
+			Rotation::Right => symmetry_by_centers([31, 22, 4, 28, 49, 25]),
+			Rotation::Top => symmetry_by_centers([4, 31, 22, 25, 28, 49]),
+			Rotation::Front => symmetry_by_centers([28, 4, 25, 49, 31, 22]),
+		}
+	}
+}
+
+
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Alg {
+	moves: Vec<Move>,
+}
+impl Alg {
+	pub fn new(moves: Vec<Move>) -> Self {
+		Self { moves }
+	}
+
+	pub fn moves(&self) -> &[Move] {
+		&self.moves
+	}
+
+	pub fn into_moves(self) -> Vec<Move> {
+		self.moves
+	}
+
+	pub fn len(&self) -> usize {
+		self.moves.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.moves.is_empty()
+	}
+
+	/// ```
+	/// # use rubiks_cube_solver_rs::{Alg, Move};
+	/// let alg = Alg::from_notation("[R: [U, F']]").unwrap();
+	/// assert_eq!("R U F' U' F R'", alg.to_notation());
+	/// assert_eq!(Alg::conjugate(&Alg::new(vec![Move::Right]), &Alg::from_notation("[U, F']").unwrap()), alg);
+	/// ```
+	pub fn from_notation(notation: &str) -> Result<Self, String> {
+		let spaced = notation.replace('[', " [ ").replace(']', " ] ").replace(':', " : ").replace(',', " , ");
+		let tokens: Vec<&str> = spaced.split_whitespace().collect();
+		let (alg, rest) = Self::parse_sequence(&tokens)?;
+		match rest.first() {
+			None => Ok(alg),
+			Some(token) => Err(format!("unexpected `{token}`")),
+		}
+	}
+
+	/// Parses moves and brackets up to a token closing or separating a bracket, returns the rest of tokens.
+	fn parse_sequence<'a, 'b>(mut tokens: &'a [&'b str]) -> Result<(Self, &'a [&'b str]), String> {
+		let mut moves = vec![];
+		loop {
+			match tokens {
+				[] | ["]" | ":" | ",", ..] => return Ok((Self::new(moves), tokens)),
+				["[", rest @ ..] => {
+					let (a, rest) = Self::parse_sequence(rest)?;
+					let (separator, rest) = rest.split_first().ok_or("unclosed `[`")?;
+					let (b, rest) = Self::parse_sequence(rest)?;
+					let ["]", rest @ ..] = rest else { return Err("unclosed `[`".to_string()) };
+					let alg = match *separator {
+						":" => Self::conjugate(&a, &b),
+						"," => Self::commutator(&a, &b),
+						_ => return Err(format!("expected `:` or `,` in brackets, found `{separator}`")),
+					};
+					moves.extend(alg.moves);
+					tokens = rest;
+				}
+				[token, rest @ ..] => {
+					moves.extend(Move::moves_from_notation(token)?);
+					tokens = rest;
+				}
+			}
+		}
+	}
+
+	pub fn to_notation(&self) -> String {
+		Move::moves_to_notation(&self.moves)
+	}
+
+	pub fn inverse(&self) -> Self {
+		Self::new(Move::inverse_moves(&self.moves))
+	}
+
+	/// Algorithm doing to `rc.symmetric(s)` what this one does to `rc`.
+	pub fn symmetric(&self, s: usize) -> Self {
+		Self::new(self.moves.iter().map(|move_| move_.symmetric(s)).collect())
+	}
+
+	/// Mirror image, e.g. `R U` is `L' U'` mirrored left-right.
+	pub fn mirrored(&self, mirror: Mirror) -> Self {
+		self.symmetric(mirror.symmetry())
+	}
+
+	/// Same algorithm performed after `rotation`, e.g. `R` is `F` after `Rotation::Top`.
+	pub fn rotated(&self, rotation: Rotation) -> Self {
+		self.symmetric(rotation.symmetry())
+	}
+
+	pub fn repeat(&self, n: usize) -> Self {
+		Self::new(std::iter::repeat_n(self.moves.as_slice(), n).flatten().cloned().collect())
+	}
+
+	pub fn concat(&self, other: &Self) -> Self {
+		Self::new([self.moves.as_slice(), &other.moves].concat())
+	}
+
+	/// `[A: B]` = `A B A'`.
+	pub fn conjugate(a: &Self, b: &Self) -> Self {
+		a.concat(b).concat(&a.inverse())
+	}
+
+	/// `[A, B]` = `A B A' B'`.
+	pub fn commutator(a: &Self, b: &Self) -> Self {
+		a.concat(b).concat(&a.inverse()).concat(&b.inverse())
+	}
+}
+impl From<Vec<Move>> for Alg {
+	fn from(moves: Vec<Move>) -> Self {
+		Self::new(moves)
+	}
+}
+impl AsRef<[Move]> for Alg {
+	fn as_ref(&self) -> &[Move] {
+		&self.moves
+	}
+}
+impl FromStr for Alg {
+	type Err = String;
+
+	fn from_str(notation: &str) -> Result<Self, String> {
+		Self::from_notation(notation)
+	}
+}
+impl fmt::Display for Alg {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(&self.to_notation())
+	}
+}
+
+
+
+impl RubiksCube {
+	pub fn apply(&mut self, alg: &Alg) {
+		self.make_moves(alg.moves());
+	}
+}
+
+
+
+#[test]
+fn alg() {
+	let alg = |notation: &str| Alg::from_notation(notation).unwrap();
+	let sexy = alg("[R, U]");
+	assert_eq!(alg("R U R' U'"), sexy);
+	assert_eq!(alg("R U R'"), alg("[R: U]"));
+	assert_eq!(alg("R2 U R' U' R'"), alg("[R: [R, U]]"));
+	assert_eq!(alg(""), Alg::default());
+	for notation in ["[R U", "[R U]", "[R: U, F]", "R ]", "R Q"] {
+		assert!(Alg::from_notation(notation).is_err(), "{notation}");
+	}
+	assert_eq!(Ok(sexy.clone()), sexy.to_string().parse());
+
+	// sexy move has order 6
+	let mut rc = RubiksCube::new();
+	rc.apply(&sexy.repeat(5));
+	assert_ne!(RubiksCube::new(), rc);
+	rc.apply(&sexy);
+	assert_eq!(RubiksCube::new(), rc);
+	rc.apply(&sexy);
+	rc.apply(&sexy.inverse());
+	assert_eq!(RubiksCube::new(), rc);
+
+	assert_eq!(alg("L' U'"), alg("R U").mirrored(Mirror::LeftRight));
+	assert_eq!(alg("R' D' F'"), alg("R U F").mirrored(Mirror::TopBottom));
+	assert_eq!(alg("F U B L D R"), alg("R U L F D B").rotated(Rotation::Top));
+	assert_eq!(alg("R B L U F D"), alg("R U L F D B").rotated(Rotation::Right));
+	assert_eq!(alg("D R U F L B"), alg("R U L F D B").rotated(Rotation::Front));
+	assert_eq!(alg("X Y Z"), alg("X Y Z").rotated(Rotation::Top).rotated(Rotation::Top).rotated(Rotation::Top).rotated(Rotation::Top));
+
+	// mirrored and rotated algorithms do the same to the mirrored and rotated cube
+	use rand::{rngs::StdRng, SeedableRng};
+	let rng = &mut StdRng::seed_from_u64(0);
+	let rc = RubiksCube::new_shuffled(20, rng);
+	let scramble = Alg::new(RubiksCube::new().shuffle(20, rng));
+	for s in [Mirror::LeftRight.symmetry(), Mirror::TopBottom.symmetry(), Rotation::Right.symmetry(), Rotation::Front.symmetry()] {
+		let mut rc_moved = rc.clone();
+		rc_moved.apply(&scramble);
+		let mut rc_symmetric_moved = rc.symmetric(s);
+		rc_symmetric_moved.apply(&scramble.symmetric(s));
+		assert_eq!(rc_moved.symmetric(s), rc_symmetric_moved);
+	}
+}
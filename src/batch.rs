@@ -0,0 +1,88 @@
+//! Batch mode: solves scrambles from a file or stdin, one per line, in parallel across the rayon pool.
+//!
+//! Scramble is either moves in notation (`F U' R2 ...`) from the solved cube, or 54 facelet letters (`RubiksCube::from_facelets`).
+//! Output is one tab separated line per (non empty) input line, in input order:
+//! line number, solver name, solution length, time in seconds and solution (or error),
+//! or with `--json` one `SolveResult` (or error) object per line.
+
+use std::{
+	fs,
+	io,
+	path::Path,
+	sync::atomic,
+};
+
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::{solve_result::SolveResult, solver_by_name, Move, RubiksCube, VERBOSE};
+
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+	/// tab separated values, with header line.
+	Tsv,
+	/// `--json`, one object per line.
+	#[cfg(feature = "serde")]
+	Json,
+}
+
+pub fn run(solver_name: &str, input: Option<&Path>, format: OutputFormat) {
+	let solver = solver_by_name(solver_name).unwrap_or_else(|| panic!("unknown solver: {solver_name}"));
+	let text = match input {
+		Some(path) => fs::read_to_string(path),
+		None => io::read_to_string(io::stdin()),
+	}.expect("can't read scrambles");
+	let lines: Vec<(usize, &str)> = text.lines()
+		.enumerate()
+		.filter(|(_, line)| !line.trim().is_empty())
+		.map(|(i, line)| (i + 1, line))
+		.collect();
+
+	VERBOSE.store(false, atomic::Ordering::Relaxed);
+	// builds shared precomputation (move tables, solved side layers) once, and outside of any scramble's time
+	let mut rc = RubiksCube::new();
+	rc.make_move(Move::Front);
+	solver(&rc);
+
+	let results: Vec<(usize, Result<SolveResult, String>)> = lines
+		.into_par_iter()
+		.map(|(line_number, line)| {
+			(line_number, RubiksCube::parse_scramble(line).map(|rc| SolveResult::solve(solver_name, solver, &rc)))
+		})
+		.collect();
+
+	match format {
+		OutputFormat::Tsv => {
+			println!("line\tsolver\tlength\ttime_s\tsolution");
+			for (line_number, result) in results {
+				match result {
+					Ok(result) => println!(
+						"{line_number}\t{solver_name}\t{}\t{:.3}\t{}",
+						result.lengths.moves, result.time_s, Move::moves_to_notation(&result.solution)
+					),
+					Err(e) => println!("{line_number}\t{solver_name}\t\t\terror: {e}"),
+				}
+			}
+		}
+		#[cfg(feature = "serde")]
+		OutputFormat::Json => {
+			for (line_number, result) in results {
+				let value = match result {
+					Ok(result) => serde_json::json!({ "line": line_number, "result": result }),
+					Err(e) => serde_json::json!({ "line": line_number, "error": e }),
+				};
+				println!("{value}");
+			}
+		}
+	}
+}
+
+/// `solve` command: solves one scramble, printing progress (as solvers log it),
+/// then `solution: ` followed by the solution in notation as the last line.
+pub fn solve(scramble: &str, solver_name: &str) {
+	let solver = solver_by_name(solver_name).unwrap_or_else(|| panic!("unknown solver: {solver_name}"));
+	let rc = RubiksCube::parse_scramble(scramble).unwrap_or_else(|e| panic!("invalid scramble: {e}"));
+	let solution = if rc == RubiksCube::new() { vec![] } else { solver(&rc) };
+	println!("solution: {}", Move::moves_to_notation(&solution));
+}
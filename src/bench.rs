@@ -0,0 +1,192 @@
+//! Benchmark of solver variants (`bench` command): each solver solves the same seeded scrambles of each depth,
+//! one at a time, measuring wall time, peak memory, frontier sizes per ply and states per second.
+//!
+//! Prints markdown table of averages per solver and depth, and optionally writes CSV with a row per solve.
+//! Peak memory is peak resident set size of the whole process (Linux only), reset before each solve,
+//! so it includes memory the allocator kept from earlier solves.
+
+use std::{fs, path::Path, sync::atomic};
+
+use rand::Rng;
+
+use crate::{solve_result::{Ply, SolveResult}, solver_by_name, Move, RubiksCube, VERBOSE};
+
+
+
+/// Variants that differ only in representation and frontier data structure.
+pub const SOLVERS_DEFAULT: [&str; 12] = [
+	"uncompressed-sorted-vec",
+	"uncompressed-unsorted-vec-without-capacity",
+	"uncompressed-unsorted-vec-with-capacity",
+	"compressed-x2-sorted-vec",
+	"compressed-x2-unsorted-vec-without-capacity",
+	"compressed-x2-unsorted-vec-with-capacity",
+	"compressed-x3-sorted-vec",
+	"compressed-x3-unsorted-vec-without-capacity",
+	"compressed-x3-unsorted-vec-with-capacity",
+	"compressed-x3-sorted-vec-smart-without-capacity",
+	"compressed-x3-sorted-vec-smart-with-capacity-smart",
+	"packed-sorted-vec-smart-with-capacity-smart",
+];
+pub const DEPTHS_DEFAULT: [u32; 3] = [4, 5, 6];
+
+#[derive(Debug, Clone)]
+pub struct Row {
+	pub solver: String,
+	pub depth: u32,
+	/// index of scramble within its depth.
+	pub scramble: usize,
+	pub result: SolveResult,
+	pub peak_rss_bytes: Option<u64>,
+	pub states: usize,
+}
+impl Row {
+	/// `None` if solve was too fast for the clock.
+	pub fn states_per_s(&self) -> Option<f64> {
+		states_per_s(self.states, self.result.time_s)
+	}
+}
+
+fn states_per_s(states: usize, time_s: f64) -> Option<f64> {
+	(time_s > 0.0).then(|| states as f64 / time_s)
+}
+
+/// Resets peak resident set size of this process (Linux 4.0+), returns whether it could.
+fn reset_peak_rss() -> bool {
+	fs::write("/proc/self/clear_refs", "5").is_ok()
+}
+
+/// Peak resident set size of this process since start or `reset_peak_rss`.
+fn peak_rss_bytes() -> Option<u64> {
+	let status = fs::read_to_string("/proc/self/status").ok()?;
+	let line = status.lines().find(|line| line.starts_with("VmHWM:"))?;
+	let kb: u64 = line.trim_start_matches("VmHWM:").trim().trim_end_matches("kB").trim().parse().ok()?;
+	Some(kb * 1024)
+}
+
+/// Number of states generated: size of the frontier expanded in each ply.
+fn states_expanded(plies: &[Ply]) -> usize {
+	let mut moves_prev = (0, 0);
+	plies.iter()
+		.map(|ply| {
+			let states = if ply.left_moves > moves_prev.0 { ply.left_len }
+				else if ply.right_moves > moves_prev.1 { ply.right_len }
+				else { 0 };
+			moves_prev = (ply.left_moves, ply.right_moves);
+			states
+		})
+		.sum()
+}
+
+/// Solves `scrambles_n` scrambles of each of `depths` random moves (the same for every solver) by each of `solver_names`.
+pub fn rows<R: Rng + ?Sized>(solver_names: &[&str], depths: &[u32], scrambles_n: usize, rng: &mut R) -> Vec<Row> {
+	let scrambles: Vec<(u32, Vec<RubiksCube>)> = depths.iter()
+		.map(|&depth| (depth, (0..scrambles_n).map(|_| RubiksCube::new_shuffled(depth, rng)).collect()))
+		.collect();
+	let verbose = VERBOSE.swap(false, atomic::Ordering::Relaxed);
+	let mut rows = vec![];
+	for &solver_name in solver_names {
+		let solver = solver_by_name(solver_name).unwrap_or_else(|| panic!("unknown solver: {solver_name}"));
+		// builds shared precomputation (move tables), so that it isn't counted to the first scramble
+		let mut rc = RubiksCube::new();
+		rc.make_move(Move::Front);
+		solver(&rc);
+		for (depth, rcs) in &scrambles {
+			for (i, rc) in rcs.iter().enumerate() {
+				let rss_reset = reset_peak_rss();
+				let result = SolveResult::solve(solver_name, solver, rc);
+				let mut rc_solved = rc.clone();
+				rc_solved.make_moves(&result.solution);
+				assert_eq!(RubiksCube::new(), rc_solved, "{solver_name} returned wrong solution");
+				rows.push(Row {
+					solver: solver_name.to_string(),
+					depth: *depth,
+					scramble: i,
+					peak_rss_bytes: if rss_reset { peak_rss_bytes() } else { None },
+					states: states_expanded(&result.plies),
+					result,
+				});
+			}
+		}
+	}
+	VERBOSE.store(verbose, atomic::Ordering::Relaxed);
+	rows
+}
+
+/// A row per solve, frontier sizes per ply as `left_len/right_len` separated by spaces.
+pub fn to_csv(rows: &[Row]) -> String {
+	let mut csv = "solver,depth,scramble,length,time_s,peak_rss_bytes,frontier_len_max,states,states_per_s,plies\n".to_string();
+	for row in rows {
+		let plies: Vec<String> = row.result.plies.iter().map(|ply| format!("{}/{}", ply.left_len, ply.right_len)).collect();
+		csv += &format!(
+			"{},{},{},{},{:.6},{},{},{},{},{}\n",
+			row.solver, row.depth, row.scramble, row.result.lengths.moves, row.result.time_s,
+			row.peak_rss_bytes.map_or(String::new(), |bytes| bytes.to_string()),
+			row.result.frontier_len_max, row.states,
+			row.states_per_s().map_or(String::new(), |states_per_s| format!("{states_per_s:.0}")), plies.join(" "),
+		);
+	}
+	csv
+}
+
+/// Averages (maxima for memory and frontier) per solver and depth.
+pub fn to_markdown(rows: &[Row]) -> String {
+	let mut markdown = [
+		"| solver | depth | time, ms | peak RSS, MiB | max frontier | states/s |\n",
+		"| --- | ---: | ---: | ---: | ---: | ---: |\n",
+	].concat();
+	let mut groups: Vec<&[Row]> = vec![];
+	let mut rest = rows;
+	while let Some(first) = rest.first() {
+		let n = rest.iter().take_while(|row| row.solver == first.solver && row.depth == first.depth).count();
+		groups.push(&rest[..n]);
+		rest = &rest[n..];
+	}
+	for group in groups {
+		let time_s = group.iter().map(|row| row.result.time_s).sum::<f64>();
+		let states = group.iter().map(|row| row.states).sum::<usize>();
+		let peak_rss = group.iter().map(|row| row.peak_rss_bytes).max().flatten()
+			.map_or("-".to_string(), |bytes| format!("{:.1}", bytes as f64 / (1 << 20) as f64));
+		let states_per_s = states_per_s(states, time_s).map_or("-".to_string(), |states_per_s| format!("{states_per_s:.0}"));
+		markdown += &format!(
+			"| {} | {} | {:.1} | {peak_rss} | {} | {states_per_s} |\n",
+			group[0].solver, group[0].depth, 1000.0 * time_s / group.len() as f64,
+			group.iter().map(|row| row.result.frontier_len_max).max().unwrap(),
+		);
+	}
+	markdown
+}
+
+/// `bench` command: prints markdown table, and writes CSV to `output` if given.
+pub fn run<R: Rng + ?Sized>(solver_names: &[&str], depths: &[u32], scrambles_n: usize, output: Option<&Path>, rng: &mut R) {
+	let rows = rows(solver_names, depths, scrambles_n, rng);
+	print!("{}", to_markdown(&rows));
+	if let Some(output) = output {
+		fs::write(output, to_csv(&rows)).unwrap_or_else(|e| panic!("can't write {}: {e}", output.display()));
+	}
+}
+
+
+
+#[test]
+fn bench() {
+	use rand::{rngs::StdRng, SeedableRng};
+	let solver_names = ["compressed-x3-sorted-vec", "packed-sorted-vec-smart-with-capacity-smart"];
+	let rows = rows(&solver_names, &[2, 3], 2, &mut StdRng::seed_from_u64(0));
+	assert_eq!(8, rows.len());
+	// same scrambles for every solver, and both solvers are optimal
+	for (row_a, row_b) in rows[..4].iter().zip(&rows[4..]) {
+		assert_eq!(row_a.result.scramble, row_b.result.scramble);
+		assert_eq!(row_a.result.lengths.moves, row_b.result.lengths.moves);
+	}
+	assert!(rows.iter().all(|row| row.states > 0 && row.result.frontier_len_max <= row.states));
+	let csv = to_csv(&rows);
+	assert_eq!(9, csv.lines().count());
+	assert!(csv.lines().all(|line| line.split(',').count() == 10));
+	let markdown = to_markdown(&rows);
+	assert_eq!(2 + 4, markdown.lines().count());
+	assert!(markdown.contains("| packed-sorted-vec-smart-with-capacity-smart | 3 |"));
+	let mut row = rows[0].clone();
+	row.result.time_s = 0.0;
+	assert_eq!(None, row.states_per_s());
+}
@@ -0,0 +1,227 @@
+//! Checkpoints of a running search (`solve_compressed_x3_*`, `solve_packed_*`, `solve_ranked_*`, `solve_coord_*`),
+//! so a crash doesn't lose hours of work.
+//!
+//! One file per (`rc_init`, `rc_final`) pair, rewritten after every completed ply (left + right expansion)
+//! and removed when the solve returns. Recursive sub-solves get their own files, because their targets differ.
+
+use std::{
+	fs::{self, File},
+	io::{self, BufReader, BufWriter, Read, Write},
+	path::{Path, PathBuf},
+	sync::OnceLock,
+};
+
+use crate::{frontier::Frontier, CoordCube, RubiksCube, RubiksCubeCompressedX3, RubiksCubePacked, SortedVec};
+
+
+
+#[doc(hidden)]
+pub static CHECKPOINT_CONFIG: OnceLock<CheckpointConfig> = OnceLock::new();
+
+#[derive(Debug, Clone)]
+pub struct CheckpointConfig {
+	pub dir: PathBuf,
+	/// if `false`, existing checkpoints are ignored (and overwritten).
+	pub resume: bool,
+}
+
+/// Frontier item, that can be saved to checkpoint.
+pub trait CheckpointItem: Copy + Ord {
+	/// distinguishes checkpoints of different frontier types for the same cubes.
+	const NAME: &str;
+	type Bytes: AsRef<[u8]> + AsMut<[u8]> + Default;
+	fn to_bytes(self) -> Self::Bytes;
+	fn from_bytes(bytes: Self::Bytes) -> Self;
+}
+impl CheckpointItem for RubiksCubeCompressedX3 {
+	const NAME: &str = "x3";
+	type Bytes = [u8; 18];
+	fn to_bytes(self) -> Self::Bytes { RubiksCubeCompressedX3::to_bytes(self) }
+	fn from_bytes(bytes: Self::Bytes) -> Self { RubiksCubeCompressedX3::from_bytes(bytes) }
+}
+impl CheckpointItem for RubiksCubePacked {
+	const NAME: &str = "packed";
+	type Bytes = [u8; 20];
+	fn to_bytes(self) -> Self::Bytes { RubiksCubePacked::to_bytes(self) }
+	fn from_bytes(bytes: Self::Bytes) -> Self { RubiksCubePacked::from_bytes(bytes) }
+}
+impl CheckpointItem for CoordCube {
+	const NAME: &str = "coord";
+	type Bytes = [u8; 14];
+	fn to_bytes(self) -> Self::Bytes { CoordCube::to_bytes(self) }
+	fn from_bytes(bytes: Self::Bytes) -> Self { CoordCube::from_bytes(bytes) }
+}
+/// `CubieCube::rank`
+impl CheckpointItem for u128 {
+	const NAME: &str = "ranked";
+	type Bytes = [u8; 16];
+	fn to_bytes(self) -> Self::Bytes { self.to_le_bytes() }
+	fn from_bytes(bytes: Self::Bytes) -> Self { u128::from_le_bytes(bytes) }
+}
+
+#[derive(Debug)]
+pub struct Checkpoint<T: CheckpointItem> {
+	pub left_rcs: SortedVec<T>,
+	pub right_rcs: SortedVec<T>,
+	pub left_moves: u32,
+	pub right_moves: u32,
+}
+impl<T: CheckpointItem> Checkpoint<T> {
+	const MAGIC: &[u8; 8] = b"RCSCKPT1";
+
+	fn path(dir: &Path, rc_init: &RubiksCube, rc_final: &RubiksCube) -> PathBuf {
+		let hex = |rc: &RubiksCube| -> String {
+			rc.to_compressed_x3().to_bytes().iter().map(|b| format!("{b:02x}")).collect()
+		};
+		dir.join(format!("{}-{}.{}.ckpt", hex(rc_init), hex(rc_final), T::NAME))
+	}
+
+	fn write(
+		path: &Path,
+		rc_init: &RubiksCube,
+		rc_final: &RubiksCube,
+		left_moves: u32,
+		right_moves: u32,
+		left_rcs: &[T],
+		right_rcs: &[T],
+	) -> io::Result<()> {
+		let path_tmp = path.with_extension("ckpt.tmp");
+		let mut file = BufWriter::new(File::create(&path_tmp)?);
+		file.write_all(Self::MAGIC)?;
+		file.write_all(&rc_init.to_compressed_x3().to_bytes())?;
+		file.write_all(&rc_final.to_compressed_x3().to_bytes())?;
+		file.write_all(&left_moves.to_le_bytes())?;
+		file.write_all(&right_moves.to_le_bytes())?;
+		for rcs in [left_rcs, right_rcs] {
+			file.write_all(&(rcs.len() as u64).to_le_bytes())?;
+			for rc in rcs {
+				file.write_all(rc.to_bytes().as_ref())?;
+			}
+		}
+		file.into_inner().map_err(|e| e.into_error())?.sync_all()?;
+		// rename is atomic, so a crash mid-write leaves the previous checkpoint intact
+		fs::rename(path_tmp, path)
+	}
+
+	fn read(path: &Path, rc_init: &RubiksCube, rc_final: &RubiksCube) -> io::Result<Self> {
+		fn read_array<const N: usize>(file: &mut impl Read) -> io::Result<[u8; N]> {
+			let mut buf = [0; N];
+			file.read_exact(&mut buf)?;
+			Ok(buf)
+		}
+		let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {msg}", path.display()));
+		// length isn't trusted for preallocation, so a corrupt file ends with an error, not allocation failure
+		let read_rcs = |file: &mut BufReader<File>| -> io::Result<SortedVec<T>> {
+			let len = u64::from_le_bytes(read_array(file)?);
+			let mut items = vec![];
+			for _ in 0..len {
+				let mut bytes = T::Bytes::default();
+				file.read_exact(bytes.as_mut())?;
+				items.push(T::from_bytes(bytes));
+			}
+			if !items.is_sorted_by(|a, b| a < b) {
+				return Err(invalid("states aren't sorted"));
+			}
+			Ok(SortedVec { items })
+		};
+
+		let mut file = BufReader::new(File::open(path)?);
+		if &read_array::<8>(&mut file)? != Self::MAGIC {
+			return Err(invalid("not a checkpoint file"));
+		}
+		if read_array(&mut file)? != rc_init.to_compressed_x3().to_bytes()
+		|| read_array(&mut file)? != rc_final.to_compressed_x3().to_bytes() {
+			return Err(invalid("checkpoint is for another cube"));
+		}
+		let left_moves = u32::from_le_bytes(read_array(&mut file)?);
+		let right_moves = u32::from_le_bytes(read_array(&mut file)?);
+		let left_rcs = read_rcs(&mut file)?;
+		let right_rcs = read_rcs(&mut file)?;
+		Ok(Self { left_rcs, right_rcs, left_moves, right_moves })
+	}
+}
+
+
+
+/// Returns last saved state of `rc_init` -> `rc_final` search, if checkpointing and resuming are enabled.
+pub(crate) fn resume<T: CheckpointItem>(rc_init: &RubiksCube, rc_final: &RubiksCube) -> Option<Checkpoint<T>> {
+	let config = CHECKPOINT_CONFIG.get().filter(|config| config.resume)?;
+	let path = Checkpoint::<T>::path(&config.dir, rc_init, rc_final);
+	match Checkpoint::read(&path, rc_init, rc_final) {
+		Ok(checkpoint) => {
+			eprintln!(
+				"resuming from {}: left_moves: {}, right_moves: {}",
+				path.display(), checkpoint.left_moves, checkpoint.right_moves
+			);
+			Some(checkpoint)
+		}
+		Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+		Err(e) => {
+			eprintln!("WARNING: can't resume from checkpoint: {e}");
+			None
+		}
+	}
+}
+
+/// Saves state of `rc_init` -> `rc_final` search after completed ply, if checkpointing is enabled.
+pub(crate) fn save<T: CheckpointItem, F: Frontier<T>>(
+	rc_init: &RubiksCube,
+	rc_final: &RubiksCube,
+	left_moves: u32,
+	right_moves: u32,
+	left_rcs: &F,
+	right_rcs: &F,
+) {
+	let Some(config) = CHECKPOINT_CONFIG.get() else { return };
+	let result = fs::create_dir_all(&config.dir).and_then(|()| left_rcs.with_sorted_items(|left_rcs| {
+		right_rcs.with_sorted_items(|right_rcs| Checkpoint::write(
+			&Checkpoint::<T>::path(&config.dir, rc_init, rc_final),
+			rc_init, rc_final,
+			left_moves, right_moves,
+			left_rcs, right_rcs,
+		))
+	}));
+	if let Err(e) = result {
+		eprintln!("WARNING: can't save checkpoint: {e}");
+	}
+}
+
+/// Removes checkpoint of finished `rc_init` -> `rc_final` search.
+pub(crate) fn finish<T: CheckpointItem>(rc_init: &RubiksCube, rc_final: &RubiksCube) {
+	let Some(config) = CHECKPOINT_CONFIG.get() else { return };
+	let _ = fs::remove_file(Checkpoint::<T>::path(&config.dir, rc_init, rc_final));
+}
+
+
+
+#[test]
+fn write_read() {
+	use crate::Move;
+	let dir = std::env::temp_dir().join(format!("rubiks-cube-solver-rs-checkpoint-{}", std::process::id()));
+	fs::create_dir_all(&dir).unwrap();
+	let rc_init = { let mut rc = RubiksCube::new(); rc.make_moves(&[Move::Front, Move::Top]); rc };
+	let rc_final = RubiksCube::new();
+	let left_rcs = SortedVec::from_vec(rc_init.juxt().map(|rc| rc.to_compressed_x3()).to_vec());
+	let right_rcs = SortedVec::from_vec(rc_final.juxt().map(|rc| rc.to_compressed_x3()).to_vec());
+	let path = Checkpoint::<RubiksCubeCompressedX3>::path(&dir, &rc_init, &rc_final);
+	Checkpoint::write(&path, &rc_init, &rc_final, 1, 1, &left_rcs.items, &right_rcs.items).unwrap();
+	let checkpoint = Checkpoint::<RubiksCubeCompressedX3>::read(&path, &rc_init, &rc_final).unwrap();
+	assert!(Checkpoint::<RubiksCubeCompressedX3>::read(&path, &rc_final, &rc_init).is_err());
+	assert_eq!((1, 1), (checkpoint.left_moves, checkpoint.right_moves));
+	assert_eq!(left_rcs.items, checkpoint.left_rcs.items);
+	assert_eq!(right_rcs.items, checkpoint.right_rcs.items);
+
+	let mut left_rcs_unsorted = left_rcs.items.clone();
+	left_rcs_unsorted.reverse();
+	Checkpoint::write(&path, &rc_init, &rc_final, 1, 1, &left_rcs_unsorted, &right_rcs.items).unwrap();
+	let e = Checkpoint::<RubiksCubeCompressedX3>::read(&path, &rc_init, &rc_final).unwrap_err();
+	assert_eq!(io::ErrorKind::InvalidData, e.kind());
+	// huge length is an early end of file, not an allocation
+	let mut bytes = fs::read(&path).unwrap();
+	let len_offset = 8 + 18 + 18 + 4 + 4;
+	bytes[len_offset..len_offset + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+	fs::write(&path, bytes).unwrap();
+	let e = Checkpoint::<RubiksCubeCompressedX3>::read(&path, &rc_init, &rc_final).unwrap_err();
+	assert_eq!(io::ErrorKind::UnexpectedEof, e.kind());
+	fs::remove_dir_all(&dir).unwrap();
+}
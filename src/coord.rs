@@ -0,0 +1,279 @@
+//! Coordinate-level cube (`CoordCube`) with precomputed move tables,
+//! so that applying a move is a lookup per coordinate instead of five `rotate4`s on the stickers.
+//!
+//! Coordinates: corner permutation, corner twist, edge flip,
+//! and edge permutation split into halves (ordered positions of edges UR..DF and DL..BR).
+//! UD-slice (unordered positions of edges FR, FL, BL, BR) is derived from the second half,
+//! so it isn't stored, but has its own table for searches that only need it.
+//!
+//! Tables are generated on first use from `MOVE_CUBIES`, which are derived from the sticker-level moves.
+
+use std::sync::LazyLock;
+
+use crate::{
+	cubie::{rank_permutation, unrank_permutation, CubieCube, MOVE_CUBIES},
+	RubiksCube,
+};
+
+
+
+pub const CORNER_PERMS_N: usize = 40320;
+pub const TWISTS_N: usize = 2187;
+pub const FLIPS_N: usize = 2048;
+/// ordered positions of 6 edges out of 12: 12 * 11 * 10 * 9 * 8 * 7
+pub const EDGES_HALF_N: usize = 665280;
+/// unordered positions of 4 edges out of 12: C(12, 4)
+pub const SLICES_N: usize = 495;
+
+/// `TABLE[coordinate][move_ as usize]` is the coordinate after `move_`.
+type MoveTable<T> = Vec<[T; 18]>;
+
+pub static CORNER_PERM_MOVE: LazyLock<MoveTable<u16>> = LazyLock::new(|| {
+	(0..CORNER_PERMS_N).map(|corner_perm| {
+		let cc = CubieCube { cp: unrank_permutation(corner_perm as u64), ..CubieCube::SOLVED };
+		cc.juxt().map(|cc_new| rank_permutation(&cc_new.cp) as u16)
+	}).collect()
+});
+
+pub static TWIST_MOVE: LazyLock<MoveTable<u16>> = LazyLock::new(|| {
+	(0..TWISTS_N).map(|twist| {
+		let cc = CubieCube { co: twist_to_co(twist as u16), ..CubieCube::SOLVED };
+		cc.juxt().map(|cc_new| co_to_twist(&cc_new.co))
+	}).collect()
+});
+
+pub static FLIP_MOVE: LazyLock<MoveTable<u16>> = LazyLock::new(|| {
+	(0..FLIPS_N).map(|flip| {
+		let cc = CubieCube { eo: flip_to_eo(flip as u16), ..CubieCube::SOLVED };
+		cc.juxt().map(|cc_new| eo_to_flip(&cc_new.eo))
+	}).collect()
+});
+
+/// Same table for both halves, as it only tracks positions of 6 edges.
+pub static EDGES_HALF_MOVE: LazyLock<MoveTable<u32>> = LazyLock::new(|| {
+	let destinations = edge_destinations();
+	(0..EDGES_HALF_N).map(|edges_half| {
+		let positions = edges_half_to_positions(edges_half as u32);
+		destinations.map(|destination| positions_to_edges_half(positions.map(|p| destination[p as usize])))
+	}).collect()
+});
+
+pub static SLICE_MOVE: LazyLock<MoveTable<u16>> = LazyLock::new(|| {
+	let destinations = edge_destinations();
+	(0..SLICES_N).map(|slice| {
+		let positions = slice_to_positions(slice as u16);
+		destinations.map(|destination| positions_to_slice(positions.map(|p| destination[p as usize])))
+	}).collect()
+});
+
+/// `edge_destinations()[move_ as usize][i]` is the position, where `move_` puts edge from position `i`.
+fn edge_destinations() -> [[u8; 12]; 18] {
+	MOVE_CUBIES.each_ref().map(|move_cubie| {
+		let mut destination = [0; 12];
+		for (j, &i) in move_cubie.ep.iter().enumerate() {
+			destination[i as usize] = j as u8;
+		}
+		destination
+	})
+}
+
+
+
+fn co_to_twist(co: &[u8; 8]) -> u16 {
+	co[..7].iter().fold(0, |acc, &o| acc * 3 + o as u16)
+}
+fn twist_to_co(mut twist: u16) -> [u8; 8] {
+	let mut co = [0; 8];
+	for i in (0..7).rev() {
+		co[i] = (twist % 3) as u8;
+		twist /= 3;
+	}
+	co[7] = ((3 - co[..7].iter().map(|&o| o as u16).sum::<u16>() % 3) % 3) as u8;
+	co
+}
+
+fn eo_to_flip(eo: &[u8; 12]) -> u16 {
+	eo[..11].iter().fold(0, |acc, &o| acc * 2 + o as u16)
+}
+fn flip_to_eo(mut flip: u16) -> [u8; 12] {
+	let mut eo = [0; 12];
+	for i in (0..11).rev() {
+		eo[i] = (flip % 2) as u8;
+		flip /= 2;
+	}
+	eo[11] = eo[..11].iter().sum::<u8>() % 2;
+	eo
+}
+
+/// Mixed radix 12, 11, ..., 7: each digit is the rank of position among still unused ones.
+fn positions_to_edges_half(positions: [u8; 6]) -> u32 {
+	let mut used: u16 = 0;
+	let mut edges_half = 0;
+	for (i, &p) in positions.iter().enumerate() {
+		let rank = (!used & ((1 << p) - 1)).count_ones();
+		edges_half = edges_half * (12 - i as u32) + rank;
+		used |= 1 << p;
+	}
+	edges_half
+}
+fn edges_half_to_positions(mut edges_half: u32) -> [u8; 6] {
+	let mut ranks = [0; 6];
+	for i in (0..6).rev() {
+		let base = 12 - i as u32;
+		ranks[i] = edges_half % base;
+		edges_half /= base;
+	}
+	let mut used: u16 = 0;
+	ranks.map(|rank| {
+		let p = (0..12).filter(|p| used & (1 << p) == 0).nth(rank as usize).unwrap();
+		used |= 1 << p;
+		p
+	})
+}
+
+/// Combinatorial number system over sorted positions.
+fn positions_to_slice(mut positions: [u8; 4]) -> u16 {
+	positions.sort_unstable();
+	positions.iter().enumerate().map(|(k, &p)| binomial(p as u16, k as u16 + 1)).sum()
+}
+fn slice_to_positions(mut slice: u16) -> [u8; 4] {
+	let mut positions = [0; 4];
+	for k in (0..4).rev() {
+		let mut p = k as u16;
+		while binomial(p + 1, k as u16 + 1) <= slice {
+			p += 1;
+		}
+		slice -= binomial(p, k as u16 + 1);
+		positions[k] = p as u8;
+	}
+	positions
+}
+fn binomial(n: u16, k: u16) -> u16 {
+	if k > n { return 0 }
+	(0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+}
+
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CoordCube {
+	pub corner_perm: u16,
+	pub twist: u16,
+	pub flip: u16,
+	/// positions of edges UR, UF, UL, UB, DR, DF
+	pub edges_a: u32,
+	/// positions of edges DL, DB, FR, FL, BL, BR
+	pub edges_b: u32,
+}
+impl CoordCube {
+	pub fn from_cubie(cc: &CubieCube) -> Self {
+		let mut positions = [0; 12];
+		for (i, &e) in cc.ep.iter().enumerate() {
+			positions[e as usize] = i as u8;
+		}
+		Self {
+			corner_perm: rank_permutation(&cc.cp) as u16,
+			twist: co_to_twist(&cc.co),
+			flip: eo_to_flip(&cc.eo),
+			edges_a: positions_to_edges_half(positions[..6].try_into().unwrap()),
+			edges_b: positions_to_edges_half(positions[6..].try_into().unwrap()),
+		}
+	}
+
+	pub fn to_cubie(self) -> CubieCube {
+		let mut ep = [0; 12];
+		let positions_a = edges_half_to_positions(self.edges_a);
+		let positions_b = edges_half_to_positions(self.edges_b);
+		for (e, &p) in positions_a.iter().chain(&positions_b).enumerate() {
+			ep[p as usize] = e as u8;
+		}
+		CubieCube {
+			cp: unrank_permutation(self.corner_perm as u64),
+			co: twist_to_co(self.twist),
+			ep,
+			eo: flip_to_eo(self.flip),
+		}
+	}
+
+	/// # Panics
+	/// If cube isn't solvable.
+	pub fn from_rc(rc: &RubiksCube) -> Self {
+		Self::from_cubie(&CubieCube::from_rc(rc).expect("cube must be solvable"))
+	}
+
+	pub fn to_rc(self) -> RubiksCube {
+		self.to_cubie().to_rc()
+	}
+
+	pub fn slice(&self) -> u16 {
+		positions_to_slice(edges_half_to_positions(self.edges_b)[2..].try_into().unwrap())
+	}
+
+	/// Returns state after move `ALL_MOVES[m]`.
+	pub fn moved(&self, m: usize) -> Self {
+		Self {
+			corner_perm: CORNER_PERM_MOVE[self.corner_perm as usize][m],
+			twist: TWIST_MOVE[self.twist as usize][m],
+			flip: FLIP_MOVE[self.flip as usize][m],
+			edges_a: EDGES_HALF_MOVE[self.edges_a as usize][m],
+			edges_b: EDGES_HALF_MOVE[self.edges_b as usize][m],
+		}
+	}
+
+	pub fn juxt(&self) -> [Self; 18] {
+		std::array::from_fn(|m| self.moved(m))
+	}
+
+	pub fn to_bytes(self) -> [u8; 14] {
+		let mut bytes = [0; 14];
+		bytes[0..2].copy_from_slice(&self.corner_perm.to_le_bytes());
+		bytes[2..4].copy_from_slice(&self.twist.to_le_bytes());
+		bytes[4..6].copy_from_slice(&self.flip.to_le_bytes());
+		bytes[6..10].copy_from_slice(&self.edges_a.to_le_bytes());
+		bytes[10..14].copy_from_slice(&self.edges_b.to_le_bytes());
+		bytes
+	}
+
+	pub fn from_bytes(bytes: [u8; 14]) -> Self {
+		Self {
+			corner_perm: u16::from_le_bytes(bytes[0..2].try_into().unwrap()),
+			twist: u16::from_le_bytes(bytes[2..4].try_into().unwrap()),
+			flip: u16::from_le_bytes(bytes[4..6].try_into().unwrap()),
+			edges_a: u32::from_le_bytes(bytes[6..10].try_into().unwrap()),
+			edges_b: u32::from_le_bytes(bytes[10..14].try_into().unwrap()),
+		}
+	}
+}
+
+
+
+#[test]
+fn coordinates_roundtrip() {
+	for slice in 0..SLICES_N as u16 {
+		assert_eq!(slice, positions_to_slice(slice_to_positions(slice)));
+	}
+	for edges_half in (0..EDGES_HALF_N as u32).step_by(997) {
+		assert_eq!(edges_half, positions_to_edges_half(edges_half_to_positions(edges_half)));
+	}
+	use rand::{rngs::StdRng, SeedableRng};
+	let rng = &mut StdRng::seed_from_u64(0);
+	for _ in 0..100 {
+		let cc = CubieCube::from_rc(&RubiksCube::new_shuffled(30, rng)).unwrap();
+		assert_eq!(cc, CoordCube::from_cubie(&cc).to_cubie());
+	}
+}
+
+/// Checks tables against sticker-level moves (`rotate4`s).
+#[test]
+fn move_tables() {
+	use rand::{rngs::StdRng, SeedableRng};
+	let rng = &mut StdRng::seed_from_u64(0);
+	for _ in 0..100 {
+		let rc = RubiksCube::new_shuffled(30, rng);
+		let coord = CoordCube::from_rc(&rc);
+		for (m, (rc_new, coord_new)) in rc.juxt().into_iter().zip(coord.juxt()).enumerate() {
+			assert_eq!(CoordCube::from_rc(&rc_new), coord_new);
+			assert_eq!(coord_new.slice(), SLICE_MOVE[coord.slice() as usize][m]);
+		}
+	}
+}
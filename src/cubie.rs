@@ -0,0 +1,356 @@
+//! Cubie-level representation of `RubiksCube` (permutation and orientation of corners and edges),
+//! and its perfect-hash ranking into `u128`.
+//!
+//! Centers never move, so a cube is fully described by its corners and edges.
+//! Piece order and orientation conventions are the usual ones (Kociemba's):
+//! corners URF, UFL, ULB, UBR, DFR, DLF, DBL, DRB; edges UR, UF, UL, UB, DR, DF, DL, DB, FR, FL, BL, BR.
+
+use std::sync::LazyLock;
+
+use rand::Rng;
+
+use crate::{Color, RubiksCube, ALL_MOVES};
+
+
+
+/// Indices in `RubiksCube::pieces` of corner stickers, U/D sticker first, then clockwise.
+pub const CORNER_FACELETS: [[usize; 3]; 8] = [
+	[ 8, 15, 14], // URF
+	[ 6, 12, 11], // UFL
+	[ 0,  9, 20], // ULB
+	[ 2, 18, 17], // UBR
+	[47, 38, 39], // DFR
+	[45, 35, 36], // DLF
+	[51, 44, 33], // DBL
+	[53, 41, 42], // DRB
+];
+
+/// Indices in `RubiksCube::pieces` of edge stickers, U/D (or F/B for middle layer) sticker first.
+pub const EDGE_FACELETS: [[usize; 2]; 12] = [
+	[ 5, 16], // UR
+	[ 7, 13], // UF
+	[ 3, 10], // UL
+	[ 1, 19], // UB
+	[50, 40], // DR
+	[46, 37], // DF
+	[48, 34], // DL
+	[52, 43], // DB
+	[26, 27], // FR
+	[24, 23], // FL
+	[32, 21], // BL
+	[30, 29], // BR
+];
+
+pub const CORNER_NAMES: [&str; 8] = ["URF", "UFL", "ULB", "UBR", "DFR", "DLF", "DBL", "DRB"];
+pub const EDGE_NAMES: [&str; 12] = ["UR", "UF", "UL", "UB", "DR", "DF", "DL", "DB", "FR", "FL", "BL", "BR"];
+
+pub const CORNER_COLORS: [[Color; 3]; 8] = {
+	let mut colors = [[Color::W; 3]; 8];
+	let mut i = 0;
+	while i < 8 {
+		let mut n = 0;
+		while n < 3 {
+			colors[i][n] = RubiksCube::NEW[CORNER_FACELETS[i][n]];
+			n += 1;
+		}
+		i += 1;
+	}
+	colors
+};
+
+pub const EDGE_COLORS: [[Color; 2]; 12] = {
+	let mut colors = [[Color::W; 2]; 12];
+	let mut i = 0;
+	while i < 12 {
+		colors[i][0] = RubiksCube::NEW[EDGE_FACELETS[i][0]];
+		colors[i][1] = RubiksCube::NEW[EDGE_FACELETS[i][1]];
+		i += 1;
+	}
+	colors
+};
+
+const CENTERS: [usize; 6] = [4, 22, 25, 28, 31, 49];
+
+/// `MOVE_CUBIES[move_ as usize]` is the cubie cube of `move_` applied to solved cube.
+pub static MOVE_CUBIES: LazyLock<[CubieCube; 18]> = LazyLock::new(|| {
+	ALL_MOVES.map(|move_| {
+		let mut rc = RubiksCube::new();
+		rc.make_move(move_);
+		CubieCube::from_rc(&rc).unwrap()
+	})
+});
+
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CubieCubeError {
+	/// centers aren't those of `RubiksCube::new`.
+	Centers,
+	/// colors of corner at this position don't form a corner.
+	Corner(usize),
+	/// colors of edge at this position don't form an edge.
+	Edge(usize),
+	/// some corner is present more than once.
+	DuplicateCorner,
+	/// some edge is present more than once.
+	DuplicateEdge,
+	/// total corner twist isn't divisible by 3.
+	Twist,
+	/// total edge flip isn't even.
+	Flip,
+	/// corner and edge permutations have different parities.
+	Parity,
+}
+
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CubieCube {
+	/// corner permutation: `cp[i]` is the corner at position `i`.
+	pub cp: [u8; 8],
+	/// corner orientation: twist of the corner at position `i`.
+	pub co: [u8; 8],
+	/// edge permutation: `ep[i]` is the edge at position `i`.
+	pub ep: [u8; 12],
+	/// edge orientation: flip of the edge at position `i`.
+	pub eo: [u8; 12],
+}
+impl CubieCube {
+	pub const SOLVED: Self = Self {
+		cp: [0, 1, 2, 3, 4, 5, 6, 7],
+		co: [0; 8],
+		ep: [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11],
+		eo: [0; 12],
+	};
+
+	/// number of ranks (valid cube states).
+	pub const STATES_N: u128 = 40320 * 2187 * (479001600 / 2) * 2048;
+
+	pub fn from_rc(rc: &RubiksCube) -> Result<Self, CubieCubeError> {
+		if CENTERS.iter().any(|&i| rc.pieces[i] != RubiksCube::NEW[i]) {
+			return Err(CubieCubeError::Centers);
+		}
+		let [color_u, color_d] = [RubiksCube::NEW[4], RubiksCube::NEW[49]];
+		let mut self_ = Self::SOLVED;
+		for (i, facelets) in CORNER_FACELETS.iter().enumerate() {
+			let colors = facelets.map(|f| rc.pieces[f]);
+			let ori = colors.iter().position(|&c| c == color_u || c == color_d).ok_or(CubieCubeError::Corner(i))?;
+			let j = CORNER_COLORS.iter()
+				.position(|cc| cc[0] == colors[ori] && cc[1] == colors[(ori+1)%3] && cc[2] == colors[(ori+2)%3])
+				.ok_or(CubieCubeError::Corner(i))?;
+			self_.cp[i] = j as u8;
+			self_.co[i] = ori as u8;
+		}
+		for (i, facelets) in EDGE_FACELETS.iter().enumerate() {
+			let colors = facelets.map(|f| rc.pieces[f]);
+			let (j, ori) = EDGE_COLORS.iter().enumerate()
+				.find_map(|(j, ec)| {
+					if *ec == colors { Some((j, 0)) }
+					else if *ec == [colors[1], colors[0]] { Some((j, 1)) }
+					else { None }
+				})
+				.ok_or(CubieCubeError::Edge(i))?;
+			self_.ep[i] = j as u8;
+			self_.eo[i] = ori;
+		}
+		self_.verify()?;
+		Ok(self_)
+	}
+
+	pub fn to_rc(self) -> RubiksCube {
+		let mut rc = RubiksCube::new();
+		for (i, facelets) in CORNER_FACELETS.iter().enumerate() {
+			let (j, ori) = (self.cp[i] as usize, self.co[i] as usize);
+			for (n, &color) in CORNER_COLORS[j].iter().enumerate() {
+				rc.pieces[facelets[(n + ori) % 3]] = color;
+			}
+		}
+		for (i, facelets) in EDGE_FACELETS.iter().enumerate() {
+			let (j, ori) = (self.ep[i] as usize, self.eo[i] as usize);
+			for (n, &color) in EDGE_COLORS[j].iter().enumerate() {
+				rc.pieces[facelets[(n + ori) % 2]] = color;
+			}
+		}
+		rc
+	}
+
+	/// Checks that this is a reachable state.
+	pub fn verify(&self) -> Result<(), CubieCubeError> {
+		let mut corners_seen = [false; 8];
+		for &c in &self.cp {
+			if std::mem::replace(&mut corners_seen[c as usize], true) { return Err(CubieCubeError::DuplicateCorner) }
+		}
+		let mut edges_seen = [false; 12];
+		for &e in &self.ep {
+			if std::mem::replace(&mut edges_seen[e as usize], true) { return Err(CubieCubeError::DuplicateEdge) }
+		}
+		if self.co.iter().map(|&o| o as u32).sum::<u32>() % 3 != 0 { return Err(CubieCubeError::Twist) }
+		if self.eo.iter().map(|&o| o as u32).sum::<u32>() % 2 != 0 { return Err(CubieCubeError::Flip) }
+		if is_odd(&self.cp) != is_odd(&self.ep) { return Err(CubieCubeError::Parity) }
+		Ok(())
+	}
+
+	/// Returns state of applying `other` after `self`.
+	pub fn multiply(&self, other: &Self) -> Self {
+		let mut result = Self::SOLVED;
+		for i in 0..8 {
+			let j = other.cp[i] as usize;
+			result.cp[i] = self.cp[j];
+			result.co[i] = (self.co[j] + other.co[i]) % 3;
+		}
+		for i in 0..12 {
+			let j = other.ep[i] as usize;
+			result.ep[i] = self.ep[j];
+			result.eo[i] = (self.eo[j] + other.eo[i]) % 2;
+		}
+		result
+	}
+
+	pub fn juxt(&self) -> [Self; 18] {
+		MOVE_CUBIES.each_ref().map(|move_cubie| self.multiply(move_cubie))
+	}
+
+	/// Uniformly random valid state.
+	pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+		Self::unrank(rng.random_range(0..Self::STATES_N))
+	}
+
+	/// Returns unique number in `0..STATES_N`:
+	/// Lehmer codes of permutations, base 3 / base 2 orientations without the last (dependent) piece,
+	/// and edge permutation rank halved, as its parity is given by corner permutation.
+	pub fn rank(&self) -> u128 {
+		let cp = rank_permutation(&self.cp) as u128;
+		let co = self.co[..7].iter().fold(0, |acc, &o| acc * 3 + o as u128);
+		let ep = rank_permutation(&self.ep) as u128 / 2;
+		let eo = self.eo[..11].iter().fold(0, |acc, &o| acc * 2 + o as u128);
+		((cp * 2187 + co) * (479001600 / 2) + ep) * 2048 + eo
+	}
+
+	pub fn unrank(rank: u128) -> Self {
+		debug_assert!(rank < Self::STATES_N);
+		let eo = rank % 2048;
+		let rank = rank / 2048;
+		let ep = rank % (479001600 / 2);
+		let rank = rank / (479001600 / 2);
+		let co = rank % 2187;
+		let cp = rank / 2187;
+
+		let mut self_ = Self::SOLVED;
+		self_.cp = unrank_permutation(cp as u64);
+		self_.ep = unrank_permutation(2 * ep as u64);
+		// ranks `2 * ep` and `2 * ep + 1` differ only in the last two elements
+		if is_odd(&self_.ep) != is_odd(&self_.cp) {
+			self_.ep.swap(10, 11);
+		}
+		let mut co = co as u32;
+		for i in (0..7).rev() {
+			self_.co[i] = (co % 3) as u8;
+			co /= 3;
+		}
+		self_.co[7] = ((3 - self_.co[..7].iter().map(|&o| o as u32).sum::<u32>() % 3) % 3) as u8;
+		let mut eo = eo as u32;
+		for i in (0..11).rev() {
+			self_.eo[i] = (eo % 2) as u8;
+			eo /= 2;
+		}
+		self_.eo[11] = (self_.eo[..11].iter().map(|&o| o as u32).sum::<u32>() % 2) as u8;
+		self_
+	}
+}
+
+
+
+/// Lehmer code (lexicographic rank) of permutation of `0..n`.
+pub fn rank_permutation(permutation: &[u8]) -> u64 {
+	let n = permutation.len();
+	let mut rank = 0;
+	for i in 0..n {
+		let smaller_after = permutation[i+1..].iter().filter(|&&p| p < permutation[i]).count();
+		rank = rank * (n - i) as u64 + smaller_after as u64;
+	}
+	rank
+}
+
+/// Inverse of `rank_permutation`, without allocation, as it's in the hot loop of ranked solver.
+pub fn unrank_permutation<const N: usize>(mut rank: u64) -> [u8; N] {
+	let mut digits = [0; N];
+	for i in (0..N).rev() {
+		let base = (N - i) as u64;
+		digits[i] = (rank % base) as usize;
+		rank /= base;
+	}
+	let mut available: [u8; N] = std::array::from_fn(|i| i as u8);
+	let mut available_n = N;
+	digits.map(|digit| {
+		let item = available[digit];
+		available.copy_within(digit + 1..available_n, digit);
+		available_n -= 1;
+		item
+	})
+}
+
+pub fn is_odd(permutation: &[u8]) -> bool {
+	let mut inversions = 0;
+	for i in 0..permutation.len() {
+		inversions += permutation[i+1..].iter().filter(|&&p| p < permutation[i]).count();
+	}
+	inversions % 2 == 1
+}
+
+
+
+#[test]
+fn from_rc_to_rc() {
+	use rand::{rngs::StdRng, SeedableRng};
+	let rng = &mut StdRng::seed_from_u64(0);
+	assert_eq!(Ok(CubieCube::SOLVED), CubieCube::from_rc(&RubiksCube::new()));
+	for _ in 0..100 {
+		let rc = RubiksCube::new_shuffled(30, rng);
+		let cc = CubieCube::from_rc(&rc).unwrap();
+		assert_eq!(rc, cc.to_rc());
+	}
+}
+
+#[test]
+fn moves() {
+	use rand::{rngs::StdRng, SeedableRng};
+	let rng = &mut StdRng::seed_from_u64(0);
+	for _ in 0..100 {
+		let rc = RubiksCube::new_shuffled(30, rng);
+		let cc = CubieCube::from_rc(&rc).unwrap();
+		for (rc_new, cc_new) in rc.juxt().into_iter().zip(cc.juxt()) {
+			assert_eq!(rc_new, cc_new.to_rc());
+		}
+	}
+}
+
+#[test]
+fn rank_unrank() {
+	use rand::{rngs::StdRng, SeedableRng};
+	let rng = &mut StdRng::seed_from_u64(0);
+	assert_eq!(0, CubieCube::SOLVED.rank());
+	for _ in 0..1000 {
+		let cc = CubieCube::from_rc(&RubiksCube::new_shuffled(30, rng)).unwrap();
+		let rank = cc.rank();
+		assert!(rank < CubieCube::STATES_N);
+		assert_eq!(cc, CubieCube::unrank(rank));
+	}
+	for rank in [1, 12345678901234567890, CubieCube::STATES_N - 1] {
+		let cc = CubieCube::unrank(rank);
+		assert_eq!(Ok(()), cc.verify());
+		assert_eq!(rank, cc.rank());
+	}
+	for rank in 0..24 {
+		assert_eq!(rank, rank_permutation(&unrank_permutation::<4>(rank)));
+	}
+}
+
+#[test]
+fn invalid() {
+	let mut rc = RubiksCube::new();
+	rc.pieces.swap(CORNER_FACELETS[0][1], CORNER_FACELETS[0][2]);
+	assert_eq!(Err(CubieCubeError::Corner(0)), CubieCube::from_rc(&rc));
+	let mut rc = RubiksCube::new();
+	rc.pieces.swap(EDGE_FACELETS[0][0], EDGE_FACELETS[0][1]);
+	assert_eq!(Err(CubieCubeError::Flip), CubieCube::from_rc(&rc));
+}
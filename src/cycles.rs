@@ -0,0 +1,185 @@
+//! Order and cycle structure of an algorithm (`cycles` command): where it takes each corner and edge,
+//! how many repetitions bring the cube back, and which pieces it leaves alone.
+//!
+//! Cycles are printed as `(URF UFL ULB)+1`: piece at URF goes to UFL, at UFL to ULB, at ULB to URF,
+//! and each comes back twisted once clockwise (for edges, `+1` is flipped).
+
+use std::fmt;
+
+use crate::{cubie::{CubieCube, CORNER_NAMES, EDGE_NAMES, MOVE_CUBIES}, Alg, Permutation54};
+
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+	/// positions (indices into `CORNER_NAMES` or `EDGE_NAMES`), piece at each goes to the next one, at the last to the first.
+	pub positions: Vec<usize>,
+	/// twist (corners) or flip (edges) a piece gains going around the cycle once.
+	pub orientation: u8,
+}
+impl Cycle {
+	/// Repetitions bringing pieces of the cycle back, `orientations_n` is 3 for corners and 2 for edges.
+	fn order(&self, orientations_n: u8) -> u64 {
+		let len = self.positions.len() as u64;
+		if self.orientation == 0 { len } else { len * orientations_n as u64 }
+	}
+
+	fn to_string(&self, names: &[&str]) -> String {
+		let names: Vec<&str> = self.positions.iter().map(|&position| names[position]).collect();
+		let orientation = if self.orientation == 0 { String::new() } else { format!("+{}", self.orientation) };
+		format!("({}){orientation}", names.join(" "))
+	}
+}
+
+/// Cycles of pieces that move or twist in place, and fixed pieces, by position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleStructure {
+	pub corners: Vec<Cycle>,
+	pub edges: Vec<Cycle>,
+	pub corners_fixed: Vec<usize>,
+	pub edges_fixed: Vec<usize>,
+}
+impl CycleStructure {
+	pub fn from_cubie(cc: &CubieCube) -> Self {
+		let (corners, corners_fixed) = cycles(&cc.cp, &cc.co, 3);
+		let (edges, edges_fixed) = cycles(&cc.ep, &cc.eo, 2);
+		Self { corners, edges, corners_fixed, edges_fixed }
+	}
+
+	/// Least common multiple of orders of cycles.
+	pub fn order(&self) -> u64 {
+		self.corners.iter().map(|cycle| cycle.order(3))
+			.chain(self.edges.iter().map(|cycle| cycle.order(2)))
+			.fold(1, |order, cycle_order| order / gcd(order, cycle_order) * cycle_order)
+	}
+}
+impl fmt::Display for CycleStructure {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let join = |strings: Vec<String>| if strings.is_empty() { "none".to_string() } else { strings.join(" ") };
+		writeln!(f, "corners: {}", join(self.corners.iter().map(|cycle| cycle.to_string(&CORNER_NAMES)).collect()))?;
+		writeln!(f, "edges: {}", join(self.edges.iter().map(|cycle| cycle.to_string(&EDGE_NAMES)).collect()))?;
+		let fixed = self.corners_fixed.iter().map(|&i| CORNER_NAMES[i].to_string())
+			.chain(self.edges_fixed.iter().map(|&i| EDGE_NAMES[i].to_string()))
+			.collect();
+		writeln!(f, "fixed: {}", join(fixed))
+	}
+}
+
+/// Cycles of `permutation` (`permutation[i]` is piece at position `i`) with their `orientation` sums, and fixed positions.
+fn cycles(permutation: &[u8], orientation: &[u8], orientations_n: u8) -> (Vec<Cycle>, Vec<usize>) {
+	let mut destination = vec![0; permutation.len()];
+	for (position, &piece) in permutation.iter().enumerate() {
+		destination[piece as usize] = position;
+	}
+	let mut visited = vec![false; permutation.len()];
+	let (mut cycles, mut fixed) = (vec![], vec![]);
+	for start in 0..permutation.len() {
+		let mut positions = vec![];
+		let mut position = start;
+		while !visited[position] {
+			visited[position] = true;
+			positions.push(position);
+			position = destination[position];
+		}
+		if positions.is_empty() { continue }
+		let orientation = positions.iter().map(|&position| orientation[position]).sum::<u8>() % orientations_n;
+		if positions.len() == 1 && orientation == 0 {
+			fixed.push(start);
+		}
+		else {
+			cycles.push(Cycle { positions, orientation });
+		}
+	}
+	(cycles, fixed)
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+	if b == 0 { a } else { gcd(b, a % b) }
+}
+
+
+
+impl Alg {
+	/// Cubie cube of this algorithm applied to solved cube.
+	pub fn to_cubie(&self) -> CubieCube {
+		self.moves().iter().fold(CubieCube::SOLVED, |cc, move_| cc.multiply(&MOVE_CUBIES[move_.clone() as usize]))
+	}
+
+	/// `permutation[i]` is sticker this algorithm puts to index `i`, as in `Move::sticker_permutation`.
+	pub fn sticker_permutation(&self) -> [usize; 54] {
+		Permutation54::from_moves(self.moves()).indices()
+	}
+
+	pub fn cycles(&self) -> CycleStructure {
+		CycleStructure::from_cubie(&self.to_cubie())
+	}
+
+	/// Repetitions of this algorithm bringing any cube back to where it was.
+	pub fn order(&self) -> u64 {
+		self.cycles().order()
+	}
+}
+
+
+
+/// `cycles` command: prints order and cycle structure of algorithm given in notation (with brackets, see `alg`).
+#[doc(hidden)]
+pub fn run(notation: &str) {
+	let alg = Alg::from_notation(notation).unwrap_or_else(|e| panic!("invalid algorithm: {e}"));
+	let cycles = alg.cycles();
+	println!("order: {}", cycles.order());
+	print!("{cycles}");
+}
+
+
+
+#[test]
+fn cycles_and_order() {
+	use rand::Rng;
+	use crate::RubiksCube;
+	let alg = |notation: &str| Alg::from_notation(notation).unwrap();
+	assert_eq!(1, alg("").order());
+	assert_eq!(4, alg("R").order());
+	assert_eq!(6, alg("[R, U]").order());
+	assert_eq!(105, alg("R U").order());
+	assert_eq!(2, alg("R U R' U' R' F R2 U' R' U' R U R' F'").order());
+
+	// `U` turns the top face clockwise as seen from above
+	assert!(alg("U").cycles().to_string().starts_with("corners: (URF UFL ULB UBR)\nedges: (UR UF UL UB)\n"));
+	let sexy = alg("[R, U]").cycles();
+	assert_eq!(
+		"corners: (URF DFR)+1 (ULB UBR)+2\nedges: (UR UB FR)\nfixed: UFL DLF DBL DRB UF UL DR DF DL DB FL BL BR\n",
+		sexy.to_string(),
+	);
+	// T permutation swaps two corners and two edges
+	let t = alg("R U R' U' R' F R2 U' R' U' R U R' F'").cycles();
+	assert_eq!(vec![vec![0, 3]], t.corners.iter().map(|cycle| cycle.positions.clone()).collect::<Vec<_>>());
+	assert_eq!(vec![vec![0, 2]], t.edges.iter().map(|cycle| cycle.positions.clone()).collect::<Vec<_>>());
+	assert_eq!((6, 10), (t.corners_fixed.len(), t.edges_fixed.len()));
+
+	// order agrees with the cube and with the permutation of stickers
+	use rand::{rngs::StdRng, SeedableRng};
+	let rng = &mut StdRng::seed_from_u64(0);
+	for _ in 0..20 {
+		let alg = Alg::new(RubiksCube::new().shuffle(rng.random_range(1..8), rng));
+		let order = alg.order();
+		let mut rc = RubiksCube::new_shuffled(20, rng);
+		let rc_start = rc.clone();
+		for i in 1..=order {
+			rc.apply(&alg);
+			assert_eq!(i == order, rc == rc_start);
+		}
+		let permutation = alg.sticker_permutation();
+		let sticker_order = (0..54).map(|start| {
+			let mut len = 1;
+			let mut i = permutation[start];
+			while i != start {
+				i = permutation[i];
+				len += 1;
+			}
+			len
+		}).fold(1, |order, len| order / gcd(order, len) * len);
+		assert_eq!(order, sticker_order);
+		assert_eq!(alg.to_cubie().to_rc(), RubiksCube::from_pieces(std::array::from_fn(|i| RubiksCube::new().pieces()[permutation[i]])));
+	}
+}
@@ -0,0 +1,193 @@
+//! Cube diagrams as images: SVG, and PNG with `png` feature.
+//!
+//! Both are drawn from the same geometry, one quadrilateral per visible sticker.
+
+use std::{fs, path::Path};
+
+use crate::{render::{net_index, rgb}, Color, RubiksCube};
+
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagramStyle {
+	/// all 54 stickers, laid out as the net documented above `RubiksCube`.
+	Net,
+	/// top, front (indices 12..) and right (indices 15..) faces, seen from their common corner.
+	Isometric,
+}
+impl DiagramStyle {
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"net" => Some(DiagramStyle::Net),
+			"isometric" => Some(DiagramStyle::Isometric),
+			_ => None,
+		}
+	}
+}
+
+/// sticker side, in pixels.
+const STICKER: f32 = 30.0;
+const MARGIN: f32 = 5.0;
+const STROKE: f32 = 2.0;
+
+type Point = (f32, f32);
+
+#[derive(Debug, Clone)]
+struct Diagram {
+	width: f32,
+	height: f32,
+	stickers: Vec<([Point; 4], Color)>,
+}
+impl Diagram {
+	fn new(rc: &RubiksCube, style: DiagramStyle) -> Self {
+		match style {
+			DiagramStyle::Net => Self::net(rc),
+			DiagramStyle::Isometric => Self::isometric(rc),
+		}
+	}
+
+	fn net(rc: &RubiksCube) -> Self {
+		let mut stickers = vec![];
+		for row in 0..9 {
+			for column in 0..12 {
+				if let Some(index) = net_index(row, column) {
+					let (x, y) = (MARGIN + column as f32 * STICKER, MARGIN + row as f32 * STICKER);
+					let corners = [(x, y), (x + STICKER, y), (x + STICKER, y + STICKER), (x, y + STICKER)];
+					stickers.push((corners, rc.pieces()[index]));
+				}
+			}
+		}
+		Self { width: 2.0 * MARGIN + 12.0 * STICKER, height: 2.0 * MARGIN + 9.0 * STICKER, stickers }
+	}
+
+	fn isometric(rc: &RubiksCube) -> Self {
+		let face = 3.0 * STICKER;
+		let (a, b) = (face * 3f32.sqrt() / 2.0, face / 2.0);
+		// edges of the face, from the corner nearest to the viewer
+		let up_left = (-a, -b);
+		let up_right = (a, -b);
+		let down = (0.0, face);
+		let nearest = (MARGIN + a, MARGIN + face);
+		let add = |p: Point, q: Point, k: f32| (p.0 + q.0 * k, p.1 + q.1 * k);
+
+		let mut stickers = vec![];
+		// (corner of sticker 0, direction of columns, direction of rows, index of sticker 0, row stride)
+		let faces = [
+			(add(add(nearest, up_left, 1.0), up_right, 1.0), (a, b), (-a, b), 0, 3),
+			(add(nearest, up_left, 1.0), (a, b), down, 12, 12),
+			(nearest, up_right, down, 15, 12),
+		];
+		for (origin, columns, rows, index_first, stride) in faces {
+			for row in 0..3 {
+				for column in 0..3 {
+					let corner = |r: usize, c: usize| add(add(origin, columns, c as f32 / 3.0), rows, r as f32 / 3.0);
+					let corners = [corner(row, column), corner(row, column + 1), corner(row + 1, column + 1), corner(row + 1, column)];
+					stickers.push((corners, rc.pieces()[index_first + row * stride + column]));
+				}
+			}
+		}
+		Self { width: 2.0 * (MARGIN + a), height: 2.0 * (MARGIN + face), stickers }
+	}
+
+	fn to_svg(&self) -> String {
+		let mut svg = format!(
+			"<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">\n",
+			w = self.width, h = self.height,
+		);
+		for (corners, color) in &self.stickers {
+			let points: Vec<String> = corners.iter().map(|(x, y)| format!("{x:.2},{y:.2}")).collect();
+			let (r, g, b) = rgb(*color);
+			svg += &format!(
+				"<polygon points=\"{}\" fill=\"#{r:02x}{g:02x}{b:02x}\" stroke=\"#000000\" stroke-width=\"{STROKE}\" stroke-linejoin=\"round\"/>\n",
+				points.join(" "),
+			);
+		}
+		svg += "</svg>\n";
+		svg
+	}
+
+	#[cfg(feature = "png")]
+	fn to_png(&self) -> Vec<u8> {
+		use tiny_skia::{FillRule, Paint, PathBuilder, Pixmap, Stroke, Transform};
+		let mut pixmap = Pixmap::new(self.width.ceil() as u32, self.height.ceil() as u32).unwrap();
+		for (corners, color) in &self.stickers {
+			let mut path = PathBuilder::new();
+			path.move_to(corners[0].0, corners[0].1);
+			for &(x, y) in &corners[1..] {
+				path.line_to(x, y);
+			}
+			path.close();
+			let path = path.finish().unwrap();
+			let mut paint = Paint::default();
+			let (r, g, b) = rgb(*color);
+			paint.set_color_rgba8(r, g, b, 255);
+			pixmap.fill_path(&path, &paint, FillRule::Winding, Transform::identity(), None);
+			paint.set_color_rgba8(0, 0, 0, 255);
+			let stroke = Stroke { width: STROKE, line_join: tiny_skia::LineJoin::Round, ..Stroke::default() };
+			pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), None);
+		}
+		pixmap.encode_png().unwrap()
+	}
+}
+
+
+
+impl RubiksCube {
+	pub fn to_svg(&self, style: DiagramStyle) -> String {
+		Diagram::new(self, style).to_svg()
+	}
+
+	/// PNG file contents, same image as `to_svg`.
+	#[cfg(feature = "png")]
+	pub fn to_png(&self, style: DiagramStyle) -> Vec<u8> {
+		Diagram::new(self, style).to_png()
+	}
+}
+
+
+
+/// `render` command: draws cube given by `scramble` (notation or facelets, as in `batch`)
+/// to `output` (PNG if it ends with `.png`, SVG otherwise), or as SVG to stdout.
+#[doc(hidden)]
+pub fn run(scramble: &str, style: DiagramStyle, output: Option<&Path>) {
+	let rc = RubiksCube::parse_scramble(scramble).unwrap_or_else(|e| panic!("invalid scramble: {e}"));
+	let Some(output) = output else {
+		print!("{}", rc.to_svg(style));
+		return
+	};
+	let contents = if output.extension().is_some_and(|extension| extension == "png") {
+		#[cfg(feature = "png")]
+		{ rc.to_png(style) }
+		#[cfg(not(feature = "png"))]
+		panic!("PNG output requires `png` feature")
+	}
+	else {
+		rc.to_svg(style).into_bytes()
+	};
+	fs::write(output, contents).unwrap_or_else(|e| panic!("can't write {}: {e}", output.display()));
+}
+
+
+
+#[test]
+fn diagrams() {
+	use crate::Move;
+	let mut rc = RubiksCube::new();
+	rc.make_move(Move::Right);
+	let net = rc.to_svg(DiagramStyle::Net);
+	assert_eq!(54, net.matches("<polygon").count());
+	// solved cube has 9 stickers of each color, and `R` keeps it so
+	for color in crate::ALL_COLORS {
+		let (r, g, b) = rgb(color);
+		assert_eq!(9, net.matches(&format!("fill=\"#{r:02x}{g:02x}{b:02x}\"")).count());
+	}
+	let isometric = Diagram::isometric(&rc);
+	assert_eq!(27, isometric.stickers.len());
+	// front face's right column shows bottom color after `R`
+	assert_eq!(Color::W, isometric.stickers[9 + 2].1);
+	for (corners, _) in &isometric.stickers {
+		assert!(corners.iter().all(|&(x, y)| (0.0..=isometric.width).contains(&x) && (0.0..=isometric.height).contains(&y)));
+	}
+	#[cfg(feature = "png")]
+	assert!(rc.to_png(DiagramStyle::Isometric).starts_with(b"\x89PNG"));
+}
@@ -0,0 +1,319 @@
+//! Interactive editor of cube state, to enter a physical cube and solve it (`edit` command, `tui` feature).
+//!
+//! Keys: arrows/`hjkl` move the cursor over the net, `w y o r g b` set sticker color (and move to the next sticker),
+//! `:` then moves in notation and `Enter` applies them, `n` resets to solved, `s` solves, `c` cancels the solve, `q` quits.
+//! Solve runs in a child process (`solve` command), so cancelling it frees its memory and cores at once.
+
+use crate::{render::{self, RenderOptions}, CubieCube, Color, Move, RubiksCube, ALL_COLORS};
+
+
+
+#[derive(Debug, Clone)]
+pub struct Editor {
+	rc: RubiksCube,
+	/// (row, column) in the net, as in `render::net_index`.
+	cursor: (usize, usize),
+	/// moves being typed, if any.
+	input: Option<String>,
+	/// result of the last action, shown under the net.
+	message: String,
+}
+impl Editor {
+	pub fn new() -> Self {
+		Self { rc: RubiksCube::new(), cursor: (0, 3), input: None, message: String::new() }
+	}
+
+	pub fn rc(&self) -> &RubiksCube {
+		&self.rc
+	}
+
+	pub fn cursor_index(&self) -> usize {
+		render::net_index(self.cursor.0, self.cursor.1).unwrap()
+	}
+
+	/// Moves cursor by `(rows, columns)`, skipping gaps of the net, and stays if there is no sticker that way.
+	pub fn move_cursor(&mut self, rows: isize, columns: isize) {
+		let (mut row, mut column) = self.cursor;
+		loop {
+			let (Some(r), Some(c)) = (row.checked_add_signed(rows), column.checked_add_signed(columns)) else { return };
+			if r >= 9 || c >= 12 { return }
+			(row, column) = (r, c);
+			if render::net_index(row, column).is_some() {
+				self.cursor = (row, column);
+				return
+			}
+		}
+	}
+
+	/// Sets color under cursor and moves cursor to the next sticker (by index), so a face can be typed in a row.
+	pub fn set_color(&mut self, color: Color) {
+		let index = self.cursor_index();
+		let mut pieces = *self.rc.pieces();
+		pieces[index] = color;
+		self.rc = RubiksCube::from_pieces(pieces);
+		if index + 1 < 54 {
+			self.cursor = (0..9)
+				.flat_map(|row| (0..12).map(move |column| (row, column)))
+				.find(|&(row, column)| render::net_index(row, column) == Some(index + 1))
+				.unwrap();
+		}
+	}
+
+	pub fn reset(&mut self) {
+		self.rc = RubiksCube::new();
+		self.message = String::new();
+	}
+
+	pub fn apply_moves(&mut self, notation: &str) -> Result<(), String> {
+		let moves = Move::moves_from_notation(notation)?;
+		self.rc.make_moves(&moves);
+		Ok(())
+	}
+
+	/// Why the cube can't be solved, if it can't.
+	pub fn validate(&self) -> Result<(), String> {
+		for color in ALL_COLORS {
+			let n = self.rc.pieces().iter().filter(|&&c| c == color).count();
+			if n != 9 {
+				return Err(format!("{n} stickers of {}, expected 9", color.to_char()));
+			}
+		}
+		CubieCube::from_rc(&self.rc).map(|_| ()).map_err(|e| format!("{e:?}"))
+	}
+
+	/// Net with cursor, validation and the last message.
+	pub fn frame(&self, options: &RenderOptions) -> String {
+		let mut lines = vec![];
+		for row in 0..9 {
+			let line = (0..12)
+				.map(|column| match render::net_index(row, column) {
+					Some(index) => {
+						let label = if (row, column) == self.cursor { "<>" } else { "" };
+						options.sticker_labeled(self.rc.pieces()[index], label)
+					}
+					None => " ".repeat(options.sticker_width),
+				})
+				.collect::<Vec<_>>()
+				.join(&" ".repeat(options.gap));
+			for _ in 0..options.sticker_height {
+				lines.push(line.trim_end().to_string());
+			}
+		}
+		lines.push(String::new());
+		lines.push(format!("sticker {}", self.cursor_index()));
+		lines.push(match self.validate() {
+			Ok(()) => "valid".to_string(),
+			Err(e) => format!("invalid: {e}"),
+		});
+		if let Some(input) = &self.input {
+			lines.push(format!("moves: {input}_"));
+		}
+		lines.push(self.message.clone());
+		lines.join("\n") + "\n"
+	}
+}
+impl Default for Editor {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+
+
+#[cfg(feature = "tui")]
+mod tui {
+	use std::{
+		env,
+		io::{self, BufRead, BufReader, Write},
+		process::{Child, Command, Stdio},
+		sync::mpsc::{self, Receiver, TryRecvError},
+		thread,
+		time::{Duration, Instant},
+	};
+
+	use crossterm::{
+		cursor,
+		event::{self, Event, KeyCode, KeyEventKind},
+		queue,
+		terminal::{self, ClearType},
+	};
+
+	use super::Editor;
+	use crate::{render::{self, RenderOptions}, Color, Move, RubiksCube};
+
+	/// Solve running in a child process, with its output lines.
+	struct Solve {
+		child: Child,
+		lines: Receiver<String>,
+		time_begin: Instant,
+		/// last progress line.
+		progress: String,
+	}
+	impl Solve {
+		fn start(rc: &RubiksCube, solver_name: &str) -> io::Result<Self> {
+			let mut child = Command::new(env::current_exe()?)
+				.args(["solve", &rc.to_facelets(), "--solver", solver_name])
+				.stdin(Stdio::null())
+				.stdout(Stdio::piped())
+				.stderr(Stdio::null())
+				.spawn()?;
+			let stdout = child.stdout.take().unwrap();
+			let (sender, lines) = mpsc::channel();
+			thread::spawn(move || {
+				for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+					if sender.send(line).is_err() { break }
+				}
+			});
+			Ok(Self { child, lines, time_begin: Instant::now(), progress: String::new() })
+		}
+
+		/// Consumes available output, returns solution (or error) once solve has finished.
+		fn poll(&mut self) -> Option<Result<Vec<Move>, String>> {
+			loop {
+				match self.lines.try_recv() {
+					Ok(line) => match line.strip_prefix("solution: ") {
+						Some(notation) => return Some(Move::moves_from_notation(notation)),
+						None => self.progress = line,
+					},
+					Err(TryRecvError::Empty) => return None,
+					Err(TryRecvError::Disconnected) => {
+						let status = self.child.wait().map_or_else(|e| e.to_string(), |status| status.to_string());
+						return Some(Err(format!("solver exited without solution ({status})")))
+					}
+				}
+			}
+		}
+	}
+	/// Dropping a solve cancels it, also when the editor returns early with an error.
+	impl Drop for Solve {
+		fn drop(&mut self) {
+			let _ = self.child.kill();
+			let _ = self.child.wait();
+		}
+	}
+
+	pub fn run(solver_name: &str) -> io::Result<()> {
+		let mut stdout = io::stdout();
+		let options = RenderOptions::detect();
+		let mut editor = Editor::new();
+		let mut solve: Option<Solve> = None;
+		let _screen = render::RawScreen::enter()?;
+
+		loop {
+			if let Some(solve_running) = &mut solve && let Some(result) = solve_running.poll() {
+				let time_s = solve_running.time_begin.elapsed().as_secs_f64();
+				editor.message = match result {
+					Ok(solution) => format!("solution ({} moves, {time_s:.1} s): {}", solution.len(), Move::moves_to_notation(&solution)),
+					Err(e) => format!("solve failed: {e}"),
+				};
+				solve = None;
+			}
+
+			queue!(stdout, terminal::Clear(ClearType::All), cursor::MoveTo(0, 0))?;
+			write!(stdout, "{}", editor.frame(&options).replace('\n', "\r\n"))?;
+			if let Some(solve) = &solve {
+				write!(stdout, "solving with {solver_name} ({:.0} s): {}\r\n", solve.time_begin.elapsed().as_secs_f64(), solve.progress)?;
+			}
+			write!(stdout, "\r\narrows/hjkl cursor, wyorgb color, : moves, n reset, s solve, c cancel, q quit")?;
+			stdout.flush()?;
+
+			if !event::poll(Duration::from_millis(100))? { continue }
+			let Event::Key(key) = event::read()? else { continue };
+			if key.kind != KeyEventKind::Press { continue }
+
+			if let Some(input) = &mut editor.input {
+				match key.code {
+					KeyCode::Char(c) => input.push(c),
+					KeyCode::Backspace => { input.pop(); }
+					KeyCode::Esc => editor.input = None,
+					KeyCode::Enter => {
+						let notation = editor.input.take().unwrap();
+						editor.message = match editor.apply_moves(&notation) {
+							Ok(()) => format!("applied: {notation}"),
+							Err(e) => e,
+						};
+					}
+					_ => {}
+				}
+				continue
+			}
+			match key.code {
+				KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+				KeyCode::Left | KeyCode::Char('h') => editor.move_cursor(0, -1),
+				KeyCode::Right | KeyCode::Char('l') => editor.move_cursor(0, 1),
+				KeyCode::Up | KeyCode::Char('k') => editor.move_cursor(-1, 0),
+				KeyCode::Down | KeyCode::Char('j') => editor.move_cursor(1, 0),
+				KeyCode::Char(':') => editor.input = Some(String::new()),
+				KeyCode::Char('n') => editor.reset(),
+				KeyCode::Char('s') if solve.is_none() => {
+					editor.message = match editor.validate() {
+						Ok(()) => match Solve::start(editor.rc(), solver_name) {
+							Ok(solve_started) => {
+								solve = Some(solve_started);
+								String::new()
+							}
+							Err(e) => format!("can't start solve: {e}"),
+						},
+						Err(e) => format!("can't solve: {e}"),
+					};
+				}
+				KeyCode::Char('c') => if solve.take().is_some() {
+					editor.message = "solve cancelled".to_string();
+				}
+				KeyCode::Char(c) => if let Some(color) = Color::from_char(c.to_ascii_uppercase()) {
+					editor.set_color(color);
+				}
+				_ => {}
+			}
+		}
+	}
+}
+#[cfg(feature = "tui")]
+#[doc(hidden)]
+pub use tui::run;
+
+
+
+#[test]
+fn editor() {
+	let mut editor = Editor::new();
+	assert_eq!(0, editor.cursor_index());
+	editor.move_cursor(-1, 0);
+	assert_eq!(0, editor.cursor_index());
+	editor.move_cursor(0, 2);
+	editor.move_cursor(1, 0);
+	assert_eq!(5, editor.cursor_index());
+	// across the gap left of the top face, there is nothing
+	editor.move_cursor(0, -3);
+	assert_eq!(5, editor.cursor_index());
+	editor.move_cursor(0, -2);
+	assert_eq!(3, editor.cursor_index());
+	editor.move_cursor(0, -1);
+	assert_eq!(3, editor.cursor_index());
+	editor.move_cursor(3, -3);
+	assert_eq!(21, editor.cursor_index());
+
+	assert_eq!(Ok(()), editor.validate());
+	editor.set_color(Color::Y);
+	assert_eq!(22, editor.cursor_index());
+	assert!(editor.validate().is_err());
+	editor.reset();
+
+	let mut rc = RubiksCube::new();
+	rc.make_moves(&Move::moves_from_notation("R U2 F'").unwrap());
+	editor.apply_moves("R U2 F'").unwrap();
+	assert_eq!(&rc, editor.rc());
+	assert!(editor.apply_moves("R Q").is_err());
+	assert_eq!(Ok(()), editor.validate());
+
+	// two swapped stickers of one edge make a flipped edge
+	let mut pieces = *RubiksCube::new().pieces();
+	pieces.swap(7, 13);
+	let mut editor = Editor::new();
+	for (i, color) in pieces.into_iter().enumerate() {
+		assert_eq!(i, editor.cursor_index());
+		editor.set_color(color);
+	}
+	assert_eq!(&RubiksCube::from_pieces(pieces), editor.rc());
+	assert!(editor.validate().is_err());
+}
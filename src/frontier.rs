@@ -0,0 +1,273 @@
+//! Frontier of a meet-in-the-middle search: all states at exactly `n` moves from one of the targets.
+//!
+//! `SortedVec` keeps states sorted, so intersection is a linear merge of two frontiers.
+//! `HashSetFrontier` keeps them in open-addressing hash sets, so intersection is probing of the larger frontier
+//! by each state of the smaller one.
+
+use std::{
+	collections::HashSet,
+	hash::{BuildHasher, BuildHasherDefault, Hash, Hasher},
+	time::{Duration, Instant},
+};
+
+use rand::Rng;
+use rayon::{iter::{IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator, ParallelIterator}, slice::ParallelSliceMut};
+
+use crate::{RubiksCube, RubiksCubeCompressedX3, SortedVec, CORES_N};
+
+
+
+pub trait Frontier<T>: Sized {
+	/// used in benchmark output.
+	const NAME: &str;
+	fn from_item(item: T) -> Self;
+	/// Frontier of `items`, in any order and possibly repeated (e.g. read from a checkpoint).
+	fn from_items(items: Vec<T>) -> Self;
+	fn len(&self) -> usize;
+	fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+	/// Returns frontier of all states reachable from this one by one move (`juxt`).
+	fn expand(self, juxt: impl Fn(&T) -> [T; 18] + Sync) -> Self;
+	fn intersection_with(&self, other: &Self) -> Option<T>;
+	/// Calls `f` with all states in sorted order (e.g. to write a checkpoint).
+	fn with_sorted_items<R>(&self, f: impl FnOnce(&[T]) -> R) -> R;
+}
+
+impl<T: Copy + Ord + Send + Sync> Frontier<T> for SortedVec<T> {
+	const NAME: &str = "sorted-vec";
+
+	fn from_item(item: T) -> Self {
+		SortedVec::from_item(item)
+	}
+
+	fn from_items(items: Vec<T>) -> Self {
+		SortedVec::from_vec(items)
+	}
+
+	fn len(&self) -> usize {
+		SortedVec::len(self)
+	}
+
+	fn expand(self, juxt: impl Fn(&T) -> [T; 18] + Sync) -> Self {
+		let mut items_new = if CORES_N == 1 {
+			let mut items_new: Vec<T> = Vec::with_capacity(self.len() * 18);
+			for item in self.items.iter() {
+				items_new.extend(juxt(item));
+			}
+			SortedVec::from_vec(items_new)
+		}
+		else {
+			let chunk_size: usize = self.len().div_ceil(CORES_N);
+			let parts: Vec<SortedVec<T>> = self.items
+				.into_par_iter()
+				.chunks(chunk_size)
+				.map(|items| {
+					let mut items_new: Vec<T> = Vec::with_capacity(items.len() * 18);
+					for item in items.iter() {
+						items_new.extend(juxt(item));
+					}
+					SortedVec::from_vec(items_new)
+				})
+				.collect();
+			SortedVec::from_sorted_vecs(parts)
+		};
+		items_new.shrink_to_fit();
+		items_new
+	}
+
+	fn intersection_with(&self, other: &Self) -> Option<T> {
+		SortedVec::intersection_with(self, other)
+	}
+
+	fn with_sorted_items<R>(&self, f: impl FnOnce(&[T]) -> R) -> R {
+		f(&self.items)
+	}
+}
+
+
+
+/// Multiply-rotate hasher from `rustc` (`FxHasher`): states are already well mixed,
+/// so SipHash's DoS resistance is just overhead here.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FxHasher {
+	hash: u64,
+}
+impl FxHasher {
+	const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+	fn add_to_hash(&mut self, word: u64) {
+		self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(Self::SEED);
+	}
+}
+impl Hasher for FxHasher {
+	fn write(&mut self, bytes: &[u8]) {
+		let (chunks, remainder) = bytes.as_chunks::<8>();
+		for &chunk in chunks {
+			self.add_to_hash(u64::from_le_bytes(chunk));
+		}
+		for &byte in remainder {
+			self.add_to_hash(byte as u64);
+		}
+	}
+	fn write_u8(&mut self, i: u8) { self.add_to_hash(i as u64) }
+	fn write_u16(&mut self, i: u16) { self.add_to_hash(i as u64) }
+	fn write_u32(&mut self, i: u32) { self.add_to_hash(i as u64) }
+	fn write_u64(&mut self, i: u64) { self.add_to_hash(i) }
+	fn write_usize(&mut self, i: usize) { self.add_to_hash(i as u64) }
+	fn finish(&self) -> u64 { self.hash }
+}
+
+type FxBuildHasher = BuildHasherDefault<FxHasher>;
+
+/// States split into shards by hash, so that shards can be filled in parallel without locking.
+#[derive(Debug, Clone)]
+pub struct HashSetFrontier<T> {
+	shards: Vec<HashSet<T, FxBuildHasher>>,
+}
+impl<T: Hash> HashSetFrontier<T> {
+	const SHARDS_N: usize = CORES_N * 4;
+
+	/// Uses middle bits of hash: low bits select bucket and top 7 bits are control byte inside `HashSet`.
+	fn shard_of(item: &T) -> usize {
+		(FxBuildHasher::default().hash_one(item) >> 32) as usize % Self::SHARDS_N
+	}
+}
+
+impl<T: Copy + Ord + Hash + Send + Sync> Frontier<T> for HashSetFrontier<T> {
+	const NAME: &str = "hash-set";
+
+	fn from_item(item: T) -> Self {
+		Self::from_items(vec![item])
+	}
+
+	fn from_items(items: Vec<T>) -> Self {
+		let mut shards: Vec<HashSet<T, FxBuildHasher>> = (0..Self::SHARDS_N).map(|_| HashSet::default()).collect();
+		for item in items {
+			shards[Self::shard_of(&item)].insert(item);
+		}
+		Self { shards }
+	}
+
+	fn len(&self) -> usize {
+		self.shards.iter().map(|shard| shard.len()).sum()
+	}
+
+	fn expand(self, juxt: impl Fn(&T) -> [T; 18] + Sync) -> Self {
+		// `parts[i][j]` are new states from shard `i`, that belong to shard `j`
+		let parts: Vec<Vec<Vec<T>>> = self.shards
+			.par_iter()
+			.map(|shard| {
+				let mut part: Vec<Vec<T>> = (0..Self::SHARDS_N).map(|_| Vec::with_capacity(shard.len() * 18 / Self::SHARDS_N)).collect();
+				for item in shard {
+					for item_new in juxt(item) {
+						part[Self::shard_of(&item_new)].push(item_new);
+					}
+				}
+				part
+			})
+			.collect();
+		drop(self);
+		let shards = (0..Self::SHARDS_N)
+			.into_par_iter()
+			.map(|j| {
+				let capacity: usize = parts.iter().map(|part| part[j].len()).sum();
+				let mut shard = HashSet::with_capacity_and_hasher(capacity, FxBuildHasher::default());
+				for part in parts.iter() {
+					shard.extend(part[j].iter().copied());
+				}
+				shard.shrink_to_fit();
+				shard
+			})
+			.collect();
+		Self { shards }
+	}
+
+	fn intersection_with(&self, other: &Self) -> Option<T> {
+		let (smaller, larger) = if self.len() <= other.len() { (self, other) } else { (other, self) };
+		smaller.shards
+			.par_iter()
+			.zip(&larger.shards)
+			.find_map_any(|(shard_smaller, shard_larger)| {
+				shard_smaller.iter().find(|item| shard_larger.contains(item)).copied()
+			})
+	}
+
+	/// Collects and sorts a copy of all states.
+	fn with_sorted_items<R>(&self, f: impl FnOnce(&[T]) -> R) -> R {
+		let mut items: Vec<T> = self.shards.iter().flatten().copied().collect();
+		items.par_sort_unstable();
+		f(&items)
+	}
+}
+
+
+
+#[derive(Debug)]
+struct Measurement {
+	solution_len: usize,
+	duration: Duration,
+}
+
+fn measure<F: Frontier<RubiksCubeCompressedX3>>(rc: &RubiksCube) -> Measurement {
+	let time_begin = Instant::now();
+	let solution = rc.solve_compressed_x3_frontier::<F>(&RubiksCube::new());
+	let duration = time_begin.elapsed();
+	let mut rc = rc.clone();
+	rc.make_moves(&solution);
+	assert_eq!(RubiksCube::new(), rc, "{} frontier returned wrong solution", F::NAME);
+	Measurement { solution_len: solution.len(), duration }
+}
+
+/// Solves the same `scrambles_n` scrambles of `depth` random moves with both frontier backends
+/// and prints markdown table of timings.
+#[doc(hidden)]
+pub fn compare<R: Rng + ?Sized>(scrambles_n: usize, depth: u32, rng: &mut R) {
+	let scrambles: Vec<RubiksCube> = (0..scrambles_n).map(|_| RubiksCube::new_shuffled(depth, rng)).collect();
+	let mut rows: Vec<String> = vec![];
+	let mut totals = [Duration::ZERO; 2];
+	for (i, rc) in scrambles.iter().enumerate() {
+		let measurements = [
+			measure::<SortedVec<RubiksCubeCompressedX3>>(rc),
+			measure::<HashSetFrontier<RubiksCubeCompressedX3>>(rc),
+		];
+		assert_eq!(measurements[0].solution_len, measurements[1].solution_len);
+		for (total, measurement) in totals.iter_mut().zip(&measurements) {
+			*total += measurement.duration;
+		}
+		rows.push(format!(
+			"| {i} | {} | {:.3} | {:.3} |",
+			measurements[0].solution_len,
+			measurements[0].duration.as_secs_f64(),
+			measurements[1].duration.as_secs_f64(),
+		));
+	}
+	println!();
+	println!("| scramble | moves | {} (s) | {} (s) |", SortedVec::<RubiksCubeCompressedX3>::NAME, HashSetFrontier::<RubiksCubeCompressedX3>::NAME);
+	println!("|---|---|---|---|");
+	for row in rows {
+		println!("{row}");
+	}
+	println!("| total | | {:.3} | {:.3} |", totals[0].as_secs_f64(), totals[1].as_secs_f64());
+}
+
+
+
+#[test]
+fn hash_set_frontier() {
+	use rand::{rngs::StdRng, SeedableRng};
+	let rng = &mut StdRng::seed_from_u64(0);
+	let rc = RubiksCube::new_shuffled(20, rng);
+	let juxt = RubiksCubeCompressedX3::juxt;
+	let mut sorted_vec: SortedVec<RubiksCubeCompressedX3> = Frontier::from_item(rc.to_compressed_x3());
+	let mut hash_set: HashSetFrontier<RubiksCubeCompressedX3> = Frontier::from_item(rc.to_compressed_x3());
+	for _ in 0..3 {
+		sorted_vec = sorted_vec.expand(juxt);
+		hash_set = hash_set.expand(juxt);
+		hash_set.with_sorted_items(|items| assert_eq!(sorted_vec.items, items));
+	}
+	let other: HashSetFrontier<RubiksCubeCompressedX3> = Frontier::from_item(sorted_vec.items[sorted_vec.len() / 2]);
+	assert_eq!(Some(sorted_vec.items[sorted_vec.len() / 2]), hash_set.intersection_with(&other));
+	let hash_set_from_items: HashSetFrontier<RubiksCubeCompressedX3> = Frontier::from_items(sorted_vec.items.clone());
+	assert_eq!(hash_set.len(), hash_set_from_items.len());
+}
@@ -0,0 +1,207 @@
+//! On-disk store of completed frontier layers of `RubiksCubeCompressedX3`, memory mapped when loaded,
+//! so a layer costs neither recomputation nor reading into memory on later runs.
+//!
+//! File is a fixed size header followed by sorted items as raw bytes, all checked when loaded.
+//! Header records everything the items depend on (format and encoding versions, move set and depth),
+//! and a file with any of them different is rejected as stale.
+
+use std::{
+	fs::{self, File},
+	io::{self, BufWriter, Write},
+	path::PathBuf,
+	sync::LazyLock,
+};
+
+use memmap2::Mmap;
+
+use crate::{RubiksCubeCompressedX3, SortedVec, ALL_MOVES};
+
+
+
+const MAGIC: &[u8; 8] = b"RCSFRONT";
+const FORMAT_VERSION: u32 = 1;
+const ENCODING_NAME: &[u8; 8] = b"x3\0\0\0\0\0\0";
+/// bump when `RubiksCubeCompressedX3` (or `ColorTriple`) byte representation changes.
+const ENCODING_VERSION: u32 = 1;
+const ITEM_LEN: usize = size_of::<RubiksCubeCompressedX3>();
+const HEADER_LEN: usize = 64;
+
+/// FNV-1a of sticker permutations of `ALL_MOVES`, so that changing any move invalidates stored layers.
+static MOVE_SET_FINGERPRINT: LazyLock<u64> = LazyLock::new(|| {
+	let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+	for move_ in ALL_MOVES.iter() {
+		for index in move_.sticker_permutation() {
+			hash ^= index as u64;
+			hash = hash.wrapping_mul(0x100_0000_01b3);
+		}
+	}
+	hash
+});
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Header {
+	format_version: u32,
+	encoding_name: [u8; 8],
+	encoding_version: u32,
+	move_set_fingerprint: u64,
+	depth: u32,
+	item_len: u32,
+	len: u64,
+}
+impl Header {
+	fn current(depth: u32, len: usize) -> Self {
+		Self {
+			format_version: FORMAT_VERSION,
+			encoding_name: *ENCODING_NAME,
+			encoding_version: ENCODING_VERSION,
+			move_set_fingerprint: *MOVE_SET_FINGERPRINT,
+			depth,
+			item_len: ITEM_LEN as u32,
+			len: len as u64,
+		}
+	}
+
+	fn to_bytes(&self) -> [u8; HEADER_LEN] {
+		let mut bytes = [0; HEADER_LEN];
+		bytes[0..8].copy_from_slice(MAGIC);
+		bytes[8..12].copy_from_slice(&self.format_version.to_le_bytes());
+		bytes[12..20].copy_from_slice(&self.encoding_name);
+		bytes[20..24].copy_from_slice(&self.encoding_version.to_le_bytes());
+		bytes[24..32].copy_from_slice(&self.move_set_fingerprint.to_le_bytes());
+		bytes[32..36].copy_from_slice(&self.depth.to_le_bytes());
+		bytes[36..40].copy_from_slice(&self.item_len.to_le_bytes());
+		bytes[40..48].copy_from_slice(&self.len.to_le_bytes());
+		bytes
+	}
+
+	fn from_bytes(bytes: &[u8; HEADER_LEN]) -> Option<Self> {
+		if &bytes[0..8] != MAGIC { return None }
+		Some(Self {
+			format_version: u32::from_le_bytes(bytes[8..12].try_into().unwrap()),
+			encoding_name: bytes[12..20].try_into().unwrap(),
+			encoding_version: u32::from_le_bytes(bytes[20..24].try_into().unwrap()),
+			move_set_fingerprint: u64::from_le_bytes(bytes[24..32].try_into().unwrap()),
+			depth: u32::from_le_bytes(bytes[32..36].try_into().unwrap()),
+			item_len: u32::from_le_bytes(bytes[36..40].try_into().unwrap()),
+			len: u64::from_le_bytes(bytes[40..48].try_into().unwrap()),
+		})
+	}
+}
+
+
+
+/// Completed frontier layer, either computed in this run or memory mapped from `FrontierStore`.
+#[derive(Debug)]
+pub enum Layer {
+	Owned(SortedVec<RubiksCubeCompressedX3>),
+	Mapped(Mmap),
+}
+impl AsRef<[RubiksCubeCompressedX3]> for Layer {
+	fn as_ref(&self) -> &[RubiksCubeCompressedX3] {
+		match self {
+			Layer::Owned(layer) => &layer.items,
+			Layer::Mapped(mmap) => {
+				let bytes = &mmap[HEADER_LEN..];
+				// SAFETY: `RubiksCubeCompressedX3` is `repr(transparent)` over 18 `u8`s, so it has alignment 1
+				// and any bytes are valid, and `FrontierStore::load` checked that length is a multiple of its size.
+				unsafe { std::slice::from_raw_parts(bytes.as_ptr().cast(), bytes.len() / ITEM_LEN) }
+			}
+		}
+	}
+}
+
+
+
+#[derive(Debug, Clone)]
+pub struct FrontierStore {
+	dir: PathBuf,
+	/// distinguishes layers of different searches in the same `dir`.
+	name: &'static str,
+}
+impl FrontierStore {
+	pub fn new(dir: PathBuf, name: &'static str) -> Self {
+		Self { dir, name }
+	}
+
+	fn path(&self, depth: u32) -> PathBuf {
+		self.dir.join(format!("{}-{depth}.frontier", self.name))
+	}
+
+	pub fn save(&self, depth: u32, layer: &[RubiksCubeCompressedX3]) -> io::Result<()> {
+		fs::create_dir_all(&self.dir)?;
+		let path = self.path(depth);
+		let path_tmp = path.with_extension("frontier.tmp");
+		let mut file = BufWriter::new(File::create(&path_tmp)?);
+		file.write_all(&Header::current(depth, layer.len()).to_bytes())?;
+		for rc in layer.iter() {
+			file.write_all(&rc.to_bytes())?;
+		}
+		file.into_inner().map_err(|e| e.into_error())?.sync_all()?;
+		// new file replaces old one, so mappings of the old one stay valid
+		fs::rename(path_tmp, path)
+	}
+
+	/// Returns error of kind `NotFound` if layer isn't stored, and `InvalidData` if it's stale or corrupted.
+	pub fn load(&self, depth: u32) -> io::Result<Layer> {
+		let path = self.path(depth);
+		let file = File::open(&path)?;
+		// SAFETY: stored files are only ever replaced by rename, never modified in place.
+		let mmap = unsafe { Mmap::map(&file)? };
+		Self::validate(&mmap, depth).map_err(|msg| {
+			io::Error::new(io::ErrorKind::InvalidData, format!("{}: {msg}", path.display()))
+		})?;
+		Ok(Layer::Mapped(mmap))
+	}
+
+	fn validate(bytes: &[u8], depth: u32) -> Result<(), String> {
+		let header = bytes.first_chunk::<HEADER_LEN>().and_then(Header::from_bytes).ok_or("not a frontier file")?;
+		let header_expected = Header::current(depth, header.len as usize);
+		if header != header_expected {
+			return Err(format!("stale frontier: expected {header_expected:?}, found {header:?}"));
+		}
+		if bytes.len() - HEADER_LEN != header.len as usize * ITEM_LEN {
+			return Err("truncated frontier".to_string());
+		}
+		// items are used by binary search and decoded without checks, so a corrupt file is rejected here, once
+		let items = &bytes[HEADER_LEN..];
+		if items.iter().any(|&value| value >= 6 * 6 * 6) {
+			return Err("corrupted frontier: invalid color triple".to_string());
+		}
+		// byte order of items is their `Ord`, as `ColorTriple`s are compared by value
+		if !items.as_chunks::<ITEM_LEN>().0.is_sorted_by(|a, b| a < b) {
+			return Err("corrupted frontier: items aren't sorted".to_string());
+		}
+		Ok(())
+	}
+}
+
+
+
+#[test]
+fn save_load() {
+	use crate::RubiksCube;
+	let dir = std::env::temp_dir().join(format!("rubiks-cube-solver-rs-frontier-store-{}", std::process::id()));
+	let store = FrontierStore::new(dir.clone(), "test");
+	let layer = SortedVec::from_vec(RubiksCube::new().juxt().map(|rc| rc.to_compressed_x3()).to_vec());
+	store.save(1, &layer.items).unwrap();
+	let layer_loaded = store.load(1).unwrap();
+	assert_eq!(layer.items, layer_loaded.as_ref());
+	assert_eq!(io::ErrorKind::NotFound, store.load(2).unwrap_err().kind());
+
+	// same file under another depth is stale
+	fs::rename(store.path(1), store.path(2)).unwrap();
+	assert_eq!(io::ErrorKind::InvalidData, store.load(2).unwrap_err().kind());
+
+	let mut items_unsorted = layer.items.clone();
+	items_unsorted.reverse();
+	store.save(1, &items_unsorted).unwrap();
+	assert_eq!(io::ErrorKind::InvalidData, store.load(1).unwrap_err().kind());
+	// still sorted, as the last item only grows
+	store.save(1, &layer.items).unwrap();
+	let mut bytes = fs::read(store.path(1)).unwrap();
+	let last_offset = bytes.len() - ITEM_LEN;
+	bytes[last_offset] = 255;
+	fs::write(store.path(1), bytes).unwrap();
+	assert_eq!(io::ErrorKind::InvalidData, store.load(1).unwrap_err().kind());
+	fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,2618 @@
+//! Rubiks Cube Solver by meet-in-the-middle search.
+//!
+//! ```
+//! use rubiks_cube_solver_rs::{Move, RubiksCube};
+//!
+//! let mut rc = RubiksCube::new();
+//! rc.make_moves(&Move::moves_from_notation("F U R'").unwrap());
+//! let solution = rc.solve_packed_sorted_vec_smart_with_capacity_smart(&RubiksCube::new());
+//! rc.make_moves(&solution);
+//! assert_eq!(RubiksCube::new(), rc);
+//! ```
+
+use std::{cmp::{Ordering, Reverse}, collections::BinaryHeap, mem::MaybeUninit, sync::{atomic::{self, AtomicBool}, LazyLock}};
+
+use rand::Rng;
+use rayon::{iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator}, slice::ParallelSliceMut};
+
+pub mod alg;
+#[doc(hidden)]
+pub mod batch;
+#[doc(hidden)]
+pub mod bench;
+pub mod checkpoint;
+pub mod coord;
+pub mod cycles;
+pub mod cubie;
+pub mod diagram;
+pub mod editor;
+pub mod frontier;
+pub(crate) mod frontier_store;
+pub mod packed;
+pub mod permutation;
+pub mod playback;
+pub mod render;
+#[cfg(feature = "serde")]
+mod serde_impls;
+pub mod solve_result;
+pub mod solved_side;
+pub mod supercube;
+pub mod symmetry;
+pub mod two_phase;
+pub mod verify;
+
+use checkpoint::{Checkpoint, CheckpointItem};
+pub use alg::Alg;
+pub use coord::CoordCube;
+pub use cubie::CubieCube;
+pub use frontier::{Frontier, HashSetFrontier};
+pub use packed::RubiksCubePacked;
+pub use permutation::Permutation54;
+pub use solve_result::SolveResult;
+
+
+
+// const CORES_N: usize = 1;
+pub(crate) const CORES_N: usize = 10;
+
+/// if `false`, solvers don't print their progress (it's still recorded in `SolveResult`) (e.g. in batch mode, where many solves run at once).
+#[doc(hidden)]
+pub static VERBOSE: AtomicBool = AtomicBool::new(true);
+
+fn log_ply(left_moves: u32, right_moves: u32, left_rcs_len: usize, right_rcs_len: usize) {
+	solve_result::record_ply(left_moves, right_moves, left_rcs_len, right_rcs_len);
+	if VERBOSE.load(atomic::Ordering::Relaxed) {
+		println!("left_moves: {left_moves}, right_moves: {right_moves}, left_rcs.len: {left_rcs_len}, right_rsc.len: {right_rcs_len}");
+	}
+}
+
+/// Solves to `RubiksCube::new`.
+///
+/// ```
+/// # use rubiks_cube_solver_rs::{Move, RubiksCube, Solver};
+/// let solver: Solver = |rc| rc.solve_compressed_x3_sorted_vec(&RubiksCube::new());
+/// let mut rc = RubiksCube::new();
+/// rc.make_move(Move::Front);
+/// assert_eq!(vec![Move::FrontS], solver(&rc));
+/// ```
+pub type Solver = fn(&RubiksCube) -> Vec<Move>;
+
+/// Solvers by name (method name without `solve_`).
+/// All but `two-phase` find shortest solutions.
+/// `coord-*`, `ranked-*` and `two-phase` work on cubies, so they panic if cube isn't solvable
+/// (check with `CubieCube::from_rc` first).
+///
+/// ```
+/// # use rubiks_cube_solver_rs::{Move, RubiksCube, SOLVERS};
+/// let mut rc = RubiksCube::new();
+/// rc.make_moves(&Move::moves_from_notation("R U'").unwrap());
+/// // `with-solved-side-6` takes long to build its table in debug builds
+/// for (name, solver) in SOLVERS.iter().filter(|(name, _)| *name != "with-solved-side-6") {
+///     let solution = solver(&rc);
+///     assert!(*name == "two-phase" || solution.len() == 2, "{name}");
+///     let mut rc_solved = rc.clone();
+///     rc_solved.make_moves(&solution);
+///     assert_eq!(RubiksCube::new(), rc_solved, "{name}");
+/// }
+/// ```
+pub const SOLVERS: [(&str, Solver); 22] = [
+	("uncompressed-sorted-vec", |rc| rc.solve_uncompressed_sorted_vec(&RubiksCube::new())),
+	("uncompressed-unsorted-vec-without-capacity", |rc| rc.solve_uncompressed_unsorted_vec_without_capacity(&RubiksCube::new())),
+	("uncompressed-unsorted-vec-with-capacity", |rc| rc.solve_uncompressed_unsorted_vec_with_capacity(&RubiksCube::new())),
+	("compressed-x2-sorted-vec", |rc| rc.solve_compressed_x2_sorted_vec(&RubiksCube::new())),
+	("compressed-x2-unsorted-vec-without-capacity", |rc| rc.solve_compressed_x2_unsorted_vec_without_capacity(&RubiksCube::new())),
+	("compressed-x2-unsorted-vec-with-capacity", |rc| rc.solve_compressed_x2_unsorted_vec_with_capacity(&RubiksCube::new())),
+	("compressed-x3-sorted-vec", |rc| rc.solve_compressed_x3_sorted_vec(&RubiksCube::new())),
+	("compressed-x3-unsorted-vec-without-capacity", |rc| rc.solve_compressed_x3_unsorted_vec_without_capacity(&RubiksCube::new())),
+	("compressed-x3-unsorted-vec-with-capacity", |rc| rc.solve_compressed_x3_unsorted_vec_with_capacity(&RubiksCube::new())),
+	("compressed-x3-sorted-vec-smart-without-capacity", |rc| rc.solve_compressed_x3_sorted_vec_smart_without_capacity(&RubiksCube::new())),
+	("compressed-x3-sorted-vec-smart-with-capacity-smart", |rc| rc.solve_compressed_x3_sorted_vec_smart_with_capacity_smart(&RubiksCube::new())),
+	("packed-sorted-vec-smart-with-capacity-smart", |rc| rc.solve_packed_sorted_vec_smart_with_capacity_smart(&RubiksCube::new())),
+	("coord-sorted-vec-smart-with-capacity-smart", |rc| rc.solve_coord_sorted_vec_smart_with_capacity_smart(&RubiksCube::new())),
+	("ranked-sorted-vec-smart-with-capacity-smart", |rc| rc.solve_ranked_sorted_vec_smart_with_capacity_smart(&RubiksCube::new())),
+	("compressed-x3-frontier-sorted-vec", |rc| rc.solve_compressed_x3_frontier::<SortedVec<RubiksCubeCompressedX3>>(&RubiksCube::new())),
+	("compressed-x3-frontier-hash-set", |rc| rc.solve_compressed_x3_frontier::<HashSetFrontier<RubiksCubeCompressedX3>>(&RubiksCube::new())),
+	("packed-frontier-sorted-vec", |rc| rc.solve_packed_frontier::<SortedVec<RubiksCubePacked>>(&RubiksCube::new())),
+	("packed-frontier-hash-set", |rc| rc.solve_packed_frontier::<HashSetFrontier<RubiksCubePacked>>(&RubiksCube::new())),
+	("symmetry-reduced", |rc| rc.solve_symmetry_reduced()),
+	("with-solved-side-5", |rc| rc.solve_with_solved_side(5)),
+	("with-solved-side-6", |rc| rc.solve_with_solved_side(6)),
+	("two-phase", |rc| two_phase::solve(&CubieCube::from_rc(rc).expect("cube must be solvable"))),
+];
+pub const SOLVER_DEFAULT: &str = "packed-sorted-vec-smart-with-capacity-smart";
+
+/// ```
+/// # use rubiks_cube_solver_rs::{solver_by_name, Move, RubiksCube, SOLVER_DEFAULT};
+/// let solver = solver_by_name(SOLVER_DEFAULT).unwrap();
+/// let mut rc = RubiksCube::new();
+/// rc.make_moves(&Move::moves_from_notation("F2 L").unwrap());
+/// assert_eq!(3, solver(&rc).len());
+/// assert!(solver_by_name("no-such-solver").is_none());
+/// ```
+pub fn solver_by_name(name: &str) -> Option<Solver> {
+	SOLVERS.iter().find(|(solver_name, _)| *solver_name == name).map(|(_, solver)| *solver)
+}
+
+
+
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum Color { W, Y, O, R, G, B }
+pub const ALL_COLORS: [Color; 6] = {use Color::*; [W, Y, O, R, G, B]};
+impl Color {
+	pub fn to_u8(&self) -> u8 {
+		match self {
+			Color::W => 0,
+			Color::Y => 1,
+			Color::O => 2,
+			Color::R => 3,
+			Color::G => 4,
+			Color::B => 5,
+		}
+	}
+	pub fn from_u8(value: u8) -> Self {
+		match value {
+			0 => Color::W,
+			1 => Color::Y,
+			2 => Color::O,
+			3 => Color::R,
+			4 => Color::G,
+			5 => Color::B,
+			_ => unreachable!()
+		}
+	}
+	pub fn to_char(self) -> char {
+		b"WYORGB"[self.to_u8() as usize] as char
+	}
+	pub fn from_char(c: char) -> Option<Self> {
+		"WYORGB".find(c).map(|value| Self::from_u8(value as u8))
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum Move { Front, FrontS, Back, BackS, Left, LeftS, Right, RightS, Top, TopS, Bottom, BottomS, X, Xs, Y, Ys, Z, Zs }
+pub const ALL_MOVES: [Move; 18] = {use Move::*; [Front, FrontS, Back, BackS, Left, LeftS, Right, RightS, Top, TopS, Bottom, BottomS, X, Xs, Y, Ys, Z, Zs]};
+impl Move {
+	/// Returns `permutation`, such that this move puts sticker from index `permutation[i]` to index `i`.
+	pub fn sticker_permutation(&self) -> [usize; 54] {
+		let mut permutation = [0; 54];
+		for index_src in 0..54 {
+			let mut pieces = [Color::W; 54];
+			pieces[index_src] = Color::Y;
+			let mut rc = RubiksCube::from_pieces(pieces);
+			rc.make_move(self.clone());
+			let index_dst = rc.pieces.iter().position(|&color| color == Color::Y).unwrap();
+			permutation[index_dst] = index_src;
+		}
+		permutation
+	}
+
+	/// `X`, `Y` and `Z` are `L' R`, `U D'` and `F B'` (as `x`, `y` and `z` methods), not cube rotations.
+	const NOTATIONS: [&str; 18] = ["F", "F'", "B", "B'", "L", "L'", "R", "R'", "U", "U'", "D", "D'", "X", "X'", "Y", "Y'", "Z", "Z'"];
+
+	pub fn to_notation(&self) -> &'static str {
+		Self::NOTATIONS[self.clone() as usize]
+	}
+
+	pub fn from_notation(notation: &str) -> Option<Self> {
+		Self::NOTATIONS.iter().position(|&n| n == notation).map(|i| ALL_MOVES[i].clone())
+	}
+
+	/// Parses whitespace separated moves, where `F2` is `F F`.
+	///
+	/// ```
+	/// # use rubiks_cube_solver_rs::Move;
+	/// let moves = Move::moves_from_notation("F2 U'").unwrap();
+	/// assert_eq!(vec![Move::Front, Move::Front, Move::TopS], moves);
+	/// assert_eq!("F F U'", Move::moves_to_notation(&moves));
+	/// ```
+	pub fn moves_from_notation(notation: &str) -> Result<Vec<Self>, String> {
+		let mut moves: Vec<Self> = vec![];
+		for token in notation.split_whitespace() {
+			let (token_single, n) = match token.strip_suffix('2') {
+				Some(token_single) => (token_single, 2),
+				None => (token, 1),
+			};
+			let move_ = Self::from_notation(token_single).ok_or_else(|| format!("unknown move: `{token}`"))?;
+			moves.extend(std::iter::repeat_n(move_, n));
+		}
+		Ok(moves)
+	}
+
+	pub fn moves_to_notation(moves: &[Self]) -> String {
+		moves.iter().map(|move_| move_.to_notation()).collect::<Vec<_>>().join(" ")
+	}
+
+	pub fn inverse(&self) -> Self {
+		ALL_MOVES[self.clone() as usize ^ 1].clone()
+	}
+
+	/// Moves that undo `moves`.
+	pub fn inverse_moves(moves: &[Self]) -> Vec<Self> {
+		moves.iter().rev().map(Self::inverse).collect()
+	}
+
+	/// Axis of the faces this move turns: 0 for `F`/`B`/`Z`, 1 for `L`/`R`/`X`, 2 for `U`/`D`/`Y`.
+	/// Moves on the same axis commute.
+	pub fn axis(&self) -> u8 {
+		use Move::*;
+		match self {
+			Front | FrontS | Back | BackS | Z | Zs => 0,
+			Left | LeftS | Right | RightS | X | Xs => 1,
+			Top | TopS | Bottom | BottomS | Y | Ys => 2,
+		}
+	}
+}
+
+/// `REDUNDANT_AFTER[prev][move_]`: whether `move_` right after `prev` cancels it,
+/// or makes a single move together with it (as `F B'` makes `Z`).
+static REDUNDANT_AFTER: LazyLock<[[bool; 18]; 18]> = LazyLock::new(|| {
+	let rcs_single: Vec<RubiksCube> = std::iter::once(RubiksCube::new()).chain(RubiksCube::new().juxt()).collect();
+	ALL_MOVES.map(|prev| ALL_MOVES.map(|move_| {
+		let mut rc = RubiksCube::new();
+		rc.make_moves(&[prev.clone(), move_]);
+		rcs_single.contains(&rc)
+	}))
+});
+
+//       y y y
+//       y y y
+//       y y y
+// b b b r r r g g g o o o
+// b b b r r r g g g o o o
+// b b b r r r g g g o o o
+//       w w w
+//       w w w
+//       w w w
+//
+//            0  1  2
+//            3  4  5
+//            6  7  8
+//  9 10 11  12 13 14  15 16 17  18 19 20
+// 21 22 23  24 25 26  27 28 29  30 31 32
+// 33 34 35  36 37 38  39 40 41  42 43 44
+//           45 46 47
+//           48 49 50
+//           51 52 53
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RubiksCube {
+	pieces: [Color; 9*6]
+}
+impl RubiksCube {
+	const NEW: [Color; 54] = { use Color::*; [
+		       Y,Y,Y,
+		       Y,Y,Y,
+		       Y,Y,Y,
+		B,B,B, R,R,R, G,G,G, O,O,O,
+		B,B,B, R,R,R, G,G,G, O,O,O,
+		B,B,B, R,R,R, G,G,G, O,O,O,
+		       W,W,W,
+		       W,W,W,
+		       W,W,W,
+	]};
+
+	pub fn new() -> Self {
+		Self { pieces: Self::NEW }
+	}
+
+	pub fn pieces(&self) -> &[Color; 54] {
+		&self.pieces
+	}
+
+	pub fn from_pieces(pieces: [Color; 54]) -> Self {
+		Self { pieces }
+	}
+
+	/// Parses 54 color letters (`WYORGB`) in `pieces` order, ignoring whitespace, so the net above can be used as is.
+	///
+	/// ```
+	/// # use rubiks_cube_solver_rs::{Move, RubiksCube};
+	/// let mut rc = RubiksCube::new();
+	/// rc.make_move(Move::Right);
+	/// assert_eq!(Ok(rc.clone()), RubiksCube::from_facelets(&rc.to_facelets()));
+	/// assert!(RubiksCube::from_facelets("WYO").is_err());
+	/// ```
+	pub fn from_facelets(facelets: &str) -> Result<Self, String> {
+		let pieces: Vec<Color> = facelets.chars()
+			.filter(|c| !c.is_whitespace())
+			.map(|c| Color::from_char(c).ok_or_else(|| format!("unknown color: `{c}`")))
+			.collect::<Result<_, _>>()?;
+		let pieces: [Color; 54] = pieces.try_into().map_err(|pieces: Vec<Color>| format!("expected 54 colors, got {}", pieces.len()))?;
+		Ok(Self::from_pieces(pieces))
+	}
+
+	pub fn to_facelets(&self) -> String {
+		self.pieces.iter().map(|color| color.to_char()).collect()
+	}
+
+	/// Facelets if line consists of exactly 54 color letters, notation otherwise.
+	pub fn parse_scramble(line: &str) -> Result<Self, String> {
+		let letters: Vec<char> = line.chars().filter(|c| !c.is_whitespace()).collect();
+		if letters.len() == 54 && letters.iter().all(|&c| Color::from_char(c).is_some()) {
+			let rc = Self::from_facelets(line)?;
+			CubieCube::from_rc(&rc).map_err(|e| format!("unsolvable cube: {e:?}"))?;
+			Ok(rc)
+		}
+		else {
+			let mut rc = Self::new();
+			rc.make_moves(&Move::moves_from_notation(line)?);
+			Ok(rc)
+		}
+	}
+
+	pub fn new_shuffled<R: Rng + ?Sized>(n: u32, rng: &mut R) -> Self {
+		let mut self_ = Self::new();
+		self_.shuffle(n, rng);
+		self_
+	}
+
+	/// Uniformly random state (unlike `new_shuffled`, which is biased towards states near `new`),
+	/// with scramble leading to it from `new`: inverse of its two-phase solution.
+	pub fn new_random_state<R: Rng + ?Sized>(rng: &mut R) -> (Self, Vec<Move>) {
+		let cc = CubieCube::random(rng);
+		(cc.to_rc(), Move::inverse_moves(&two_phase::solve(&cc)))
+	}
+
+	/// Makes `n` random moves, none of them redundant after the previous ones:
+	/// no move cancels the previous one or makes a single move together with it (`F B'` is `Z`),
+	/// and no quarter turn comes three times in a row.
+	pub fn shuffle<R: Rng + ?Sized>(&mut self, n: u32, rng: &mut R) -> Vec<Move> {
+		let mut moves: Vec<Move> = vec![];
+		for _ in 0..n {
+			let move_ = self.shuffle_once(&moves, rng);
+			moves.push(move_);
+		}
+		moves
+	}
+
+	/// Makes one random move, that isn't redundant after `moves_prev` (see `shuffle`).
+	pub fn shuffle_once<R: Rng + ?Sized>(&mut self, moves_prev: &[Move], rng: &mut R) -> Move {
+		let candidates: Vec<&Move> = ALL_MOVES.iter()
+			.filter(|move_| match moves_prev {
+				[] => true,
+				[.., prev2, prev] if prev2 == prev && prev == *move_ => false,
+				[.., prev] => !REDUNDANT_AFTER[prev.clone() as usize][(*move_).clone() as usize],
+			})
+			.collect();
+		let move_ = candidates[rng.random_range(0..candidates.len())].clone();
+		self.make_move(move_.clone());
+		move_
+	}
+
+	pub fn make_moves(&mut self, moves: &[Move]) {
+		for move_ in moves {
+			self.make_move(move_.clone());
+		}
+	}
+
+	pub fn make_move(&mut self, move_: Move) {
+		match move_ {
+			Move::Front => self.front(),
+			Move::FrontS => self.front_s(),
+			Move::Back => self.back(),
+			Move::BackS => self.back_s(),
+			Move::Left => self.left(),
+			Move::LeftS => self.left_s(),
+			Move::Right => self.right(),
+			Move::RightS => self.right_s(),
+			Move::Top => self.top(),
+			Move::TopS => self.top_s(),
+			Move::Bottom => self.bottom(),
+			Move::BottomS => self.bottom_s(),
+			Move::X => self.x(),
+			Move::Xs => self.x_s(),
+			Move::Y => self.y(),
+			Move::Ys => self.y_s(),
+			Move::Z => self.z(),
+			Move::Zs => self.z_s(),
+		}
+	}
+
+	pub fn solve_uncompressed_sorted_vec(&self, other: &RubiksCube) -> Vec<Move> {
+		trait Solve { fn solve(&self, other: &Self) -> Vec<Move>; }
+		impl Solve for RubiksCube { fn solve(&self, other: &Self) -> Vec<Move> {
+			self.solve_uncompressed_sorted_vec(other)
+		} }
+
+		let rc_init: RubiksCube = self.clone();
+		let rc_final: RubiksCube = other.clone();
+		let mut left_rcs: SortedVec<RubiksCube> = SortedVec::from_item(rc_init.clone());
+		let mut right_rcs: SortedVec<RubiksCube> = SortedVec::from_item(rc_final.clone());
+		let mut left_moves: u32 = 0;
+		let mut right_moves: u32 = 0;
+
+		let rc_middle = loop {
+			log_ply(left_moves, right_moves, left_rcs.len(), right_rcs.len());
+
+			// let mut left_rcs_new: SortedVec<RubiksCube> = SortedVec::new();
+			let mut left_rcs_new: SortedVec<RubiksCube> = SortedVec::new();
+			if CORES_N == 1 {
+				for rc in left_rcs.items.iter() {
+					for rc_new in rc.juxt() {
+						left_rcs_new.insert(rc_new);
+					}
+				}
+			}
+			else {
+				let chunk_size: usize = left_rcs.len().div_ceil(CORES_N);
+				let left_rcs_new_parts: Vec<SortedVec<RubiksCube>> = left_rcs.items
+					.into_par_iter()
+					.chunks(chunk_size)
+					.map(|rcs| {
+						let mut rcs_new: SortedVec<RubiksCube> = SortedVec::new();
+						for rc in rcs.iter() {
+							for rc_new in rc.juxt() {
+								rcs_new.insert(rc_new);
+							}
+						}
+						rcs_new
+					})
+					.collect();
+				left_rcs_new = SortedVec::from_sorted_vecs(left_rcs_new_parts);
+			}
+			left_moves += 1;
+			left_rcs = left_rcs_new;
+
+			log_ply(left_moves, right_moves, left_rcs.len(), right_rcs.len());
+
+			if let Some(rc_middle) = left_rcs.intersection_with(&right_rcs) {
+				break rc_middle
+			}
+
+			// let mut right_rcs_new: SortedVec<RubiksCube> = SortedVec::new();
+			let mut right_rcs_new: SortedVec<RubiksCube> = SortedVec::new();
+			if CORES_N == 1 {
+				for rc in right_rcs.items.iter() {
+					for rc_new in rc.juxt() {
+						right_rcs_new.insert(rc_new);
+					}
+				}
+			}
+			else {
+				let chunk_size: usize = right_rcs.len().div_ceil(CORES_N);
+				let right_rcs_new_parts: Vec<SortedVec<RubiksCube>> = right_rcs.items
+					.into_par_iter()
+					.chunks(chunk_size)
+					.map(|rcs| {
+						let mut rcs_new: SortedVec<RubiksCube> = SortedVec::new();
+						for rc in rcs.iter() {
+							for rc_new in rc.juxt() {
+								rcs_new.insert(rc_new);
+							}
+						}
+						rcs_new
+					})
+					.collect();
+				right_rcs_new = SortedVec::from_sorted_vecs(right_rcs_new_parts);
+			}
+			right_moves += 1;
+			right_rcs = right_rcs_new;
+
+			if let Some(rc_middle) = left_rcs.intersection_with(&right_rcs) {
+				break rc_middle
+			}
+		};
+
+		// println!("rc_middle:\n{}", rc_middle.to_string1());
+
+		let solution = if left_moves + right_moves == 1 {
+			assert_eq!(right_moves, 0);
+			vec![ALL_MOVES[rc_init.juxt().into_iter().position(|rc| rc == rc_final).unwrap()].clone()]
+		}
+		else {
+			[
+				rc_init.solve(&rc_middle),
+				rc_middle.solve(&rc_final),
+			].concat()
+		};
+		verify::debug_check(self, &solution, other);
+		solution
+	}
+
+	pub fn solve_uncompressed_unsorted_vec_without_capacity(&self, other: &RubiksCube) -> Vec<Move> {
+		trait Solve { fn solve(&self, other: &Self) -> Vec<Move>; }
+		impl Solve for RubiksCube { fn solve(&self, other: &Self) -> Vec<Move> {
+			self.solve_uncompressed_unsorted_vec_without_capacity(other)
+		} }
+
+		let rc_init: RubiksCube = self.clone();
+		let rc_final: RubiksCube = other.clone();
+		let mut left_rcs: SortedVec<RubiksCube> = SortedVec::from_item(rc_init.clone());
+		let mut right_rcs: SortedVec<RubiksCube> = SortedVec::from_item(rc_final.clone());
+		let mut left_moves: u32 = 0;
+		let mut right_moves: u32 = 0;
+
+		let rc_middle = loop {
+			log_ply(left_moves, right_moves, left_rcs.len(), right_rcs.len());
+
+			let mut left_rcs_new: Vec<RubiksCube> = Vec::new();
+			if CORES_N == 1 {
+				for rc in left_rcs.items.iter() {
+					for rc_new in rc.juxt() {
+						left_rcs_new.push(rc_new);
+					}
+				}
+			}
+			else {
+				let chunk_size: usize = left_rcs.len().div_ceil(CORES_N);
+				let left_rcs_new_parts: Vec<Vec<RubiksCube>> = left_rcs.items
+					.into_par_iter()
+					.chunks(chunk_size)
+					.map(|rcs| {
+						let mut rcs_new: Vec<RubiksCube> = Vec::new();
+						for rc in rcs.iter() {
+							for rc_new in rc.juxt() {
+								rcs_new.push(rc_new);
+							}
+						}
+						rcs_new
+					})
+					.collect();
+				left_rcs_new = left_rcs_new_parts.concat();
+			}
+			left_moves += 1;
+			left_rcs = SortedVec::from_vec(left_rcs_new);
+			left_rcs.shrink_to_fit();
+
+			log_ply(left_moves, right_moves, left_rcs.len(), right_rcs.len());
+
+			if let Some(rc_middle) = left_rcs.intersection_with(&right_rcs) {
+				break rc_middle
+			}
+
+			// let mut right_rcs_new: SortedVec<RubiksCube> = SortedVec::new();
+			let mut right_rcs_new: Vec<RubiksCube> = Vec::new();
+			if CORES_N == 1 {
+				for rc in right_rcs.items.iter() {
+					for rc_new in rc.juxt() {
+						right_rcs_new.push(rc_new);
+					}
+				}
+			}
+			else {
+				let chunk_size: usize = right_rcs.len().div_ceil(CORES_N);
+				let right_rcs_new_parts: Vec<Vec<RubiksCube>> = right_rcs.items
+					.into_par_iter()
+					.chunks(chunk_size)
+					.map(|rcs| {
+						let mut rcs_new: Vec<RubiksCube> = Vec::new();
+						for rc in rcs.iter() {
+							for rc_new in rc.juxt() {
+								rcs_new.push(rc_new);
+							}
+						}
+						rcs_new
+					})
+					.collect();
+				right_rcs_new = right_rcs_new_parts.concat();
+			}
+			right_moves += 1;
+			right_rcs = SortedVec::from_vec(right_rcs_new);
+			right_rcs.shrink_to_fit();
+
+			if let Some(rc_middle) = left_rcs.intersection_with(&right_rcs) {
+				break rc_middle
+			}
+		};
+
+		// println!("rc_middle:\n{}", rc_middle.to_string1());
+
+		let solution = if left_moves + right_moves == 1 {
+			assert_eq!(right_moves, 0);
+			vec![ALL_MOVES[rc_init.juxt().into_iter().position(|rc| rc == rc_final).unwrap()].clone()]
+		}
+		else {
+			[
+				rc_init.solve(&rc_middle),
+				rc_middle.solve(&rc_final),
+			].concat()
+		};
+		verify::debug_check(self, &solution, other);
+		solution
+	}
+
+	pub fn solve_uncompressed_unsorted_vec_with_capacity(&self, other: &RubiksCube) -> Vec<Move> {
+		trait Solve { fn solve(&self, other: &Self) -> Vec<Move>; }
+		impl Solve for RubiksCube { fn solve(&self, other: &Self) -> Vec<Move> {
+			self.solve_uncompressed_unsorted_vec_with_capacity(other)
+		} }
+
+		let rc_init: RubiksCube = self.clone();
+		let rc_final: RubiksCube = other.clone();
+		let mut left_rcs: SortedVec<RubiksCube> = SortedVec::from_item(rc_init.clone());
+		let mut right_rcs: SortedVec<RubiksCube> = SortedVec::from_item(rc_final.clone());
+		let mut left_moves: u32 = 0;
+		let mut right_moves: u32 = 0;
+
+		let rc_middle = loop {
+			log_ply(left_moves, right_moves, left_rcs.len(), right_rcs.len());
+
+			/// approximate array size growth rate
+			const GROWTH_RATE: usize = 13;
+
+			let mut left_rcs_new: Vec<RubiksCube> = Vec::with_capacity(left_rcs.len() * GROWTH_RATE);
+			if CORES_N == 1 {
+				for rc in left_rcs.items.iter() {
+					for rc_new in rc.juxt() {
+						left_rcs_new.push(rc_new);
+					}
+				}
+			}
+			else {
+				let chunk_size: usize = left_rcs.len().div_ceil(CORES_N);
+				let left_rcs_new_parts: Vec<Vec<RubiksCube>> = left_rcs.items
+					.into_par_iter()
+					.chunks(chunk_size)
+					.map(|rcs| {
+						let mut rcs_new: Vec<RubiksCube> = Vec::with_capacity(chunk_size * GROWTH_RATE);
+						for rc in rcs.iter() {
+							for rc_new in rc.juxt() {
+								rcs_new.push(rc_new);
+							}
+						}
+						rcs_new
+					})
+					.collect();
+				left_rcs_new = left_rcs_new_parts.concat();
+			}
+			left_moves += 1;
+			left_rcs = SortedVec::from_vec(left_rcs_new);
+			left_rcs.shrink_to_fit();
+
+			log_ply(left_moves, right_moves, left_rcs.len(), right_rcs.len());
+
+			if let Some(rc_middle) = left_rcs.intersection_with(&right_rcs) {
+				break rc_middle
+			}
+
+			// let mut right_rcs_new: SortedVec<RubiksCube> = SortedVec::new();
+			let mut right_rcs_new: Vec<RubiksCube> = Vec::with_capacity(right_rcs.len() * GROWTH_RATE);
+			if CORES_N == 1 {
+				for rc in right_rcs.items.iter() {
+					for rc_new in rc.juxt() {
+						right_rcs_new.push(rc_new);
+					}
+				}
+			}
+			else {
+				let chunk_size: usize = right_rcs.len().div_ceil(CORES_N);
+				let right_rcs_new_parts: Vec<Vec<RubiksCube>> = right_rcs.items
+					.into_par_iter()
+					.chunks(chunk_size)
+					.map(|rcs| {
+						let mut rcs_new: Vec<RubiksCube> = Vec::with_capacity(chunk_size * GROWTH_RATE);
+						for rc in rcs.iter() {
+							for rc_new in rc.juxt() {
+								rcs_new.push(rc_new);
+							}
+						}
+						rcs_new
+					})
+					.collect();
+				right_rcs_new = right_rcs_new_parts.concat();
+			}
+			right_moves += 1;
+			right_rcs = SortedVec::from_vec(right_rcs_new);
+			right_rcs.shrink_to_fit();
+
+			if let Some(rc_middle) = left_rcs.intersection_with(&right_rcs) {
+				break rc_middle
+			}
+		};
+
+		// println!("rc_middle:\n{}", rc_middle.to_string1());
+
+		let solution = if left_moves + right_moves == 1 {
+			assert_eq!(right_moves, 0);
+			vec![ALL_MOVES[rc_init.juxt().into_iter().position(|rc| rc == rc_final).unwrap()].clone()]
+		}
+		else {
+			[
+				rc_init.solve(&rc_middle),
+				rc_middle.solve(&rc_final),
+			].concat()
+		};
+		verify::debug_check(self, &solution, other);
+		solution
+	}
+
+
+
+	pub fn solve_compressed_x2_sorted_vec(&self, other: &RubiksCube) -> Vec<Move> {
+		trait Solve { fn solve(&self, other: &Self) -> Vec<Move>; }
+		impl Solve for RubiksCube { fn solve(&self, other: &Self) -> Vec<Move> {
+			self.solve_compressed_x2_sorted_vec(other)
+		} }
+
+		let rc_init: RubiksCube = self.clone();
+		let rc_final: RubiksCube = other.clone();
+		let mut left_rcs: SortedVec<RubiksCubeCompressedX2> = SortedVec::from_item(rc_init.to_compressed_x2());
+		let mut right_rcs: SortedVec<RubiksCubeCompressedX2> = SortedVec::from_item(rc_final.to_compressed_x2());
+		let mut left_moves: u32 = 0;
+		let mut right_moves: u32 = 0;
+
+		let rc_middle = loop {
+			log_ply(left_moves, right_moves, left_rcs.len(), right_rcs.len());
+
+			let mut left_rcs_new: SortedVec<RubiksCubeCompressedX2> = SortedVec::new();
+			if CORES_N == 1 {
+				for rc in left_rcs.items.iter() {
+					for rc_new in rc.to_rc().juxt() {
+						left_rcs_new.insert(rc_new.to_compressed_x2());
+					}
+				}
+			}
+			else {
+				let chunk_size: usize = left_rcs.len().div_ceil(CORES_N);
+				let left_rcs_new_parts: Vec<SortedVec<RubiksCubeCompressedX2>> = left_rcs.items
+					.into_par_iter()
+					.chunks(chunk_size)
+					.map(|rcs| {
+						let mut rcs_new: SortedVec<RubiksCubeCompressedX2> = SortedVec::new();
+						for rc in rcs.iter() {
+							for rc_new in rc.to_rc().juxt() {
+								rcs_new.insert(rc_new.to_compressed_x2());
+							}
+						}
+						rcs_new
+					})
+					.collect();
+				left_rcs_new = SortedVec::from_sorted_vecs(left_rcs_new_parts);
+			}
+			left_moves += 1;
+			left_rcs = left_rcs_new;
+
+			log_ply(left_moves, right_moves, left_rcs.len(), right_rcs.len());
+
+			if let Some(rc_middle) = left_rcs.intersection_with(&right_rcs) {
+				break rc_middle
+			}
+
+			let mut right_rcs_new: SortedVec<RubiksCubeCompressedX2> = SortedVec::new();
+			if CORES_N == 1 {
+				for rc in right_rcs.items.iter() {
+					for rc_new in rc.to_rc().juxt() {
+						right_rcs_new.insert(rc_new.to_compressed_x2());
+					}
+				}
+			}
+			else {
+				let chunk_size: usize = right_rcs.len().div_ceil(CORES_N);
+				let right_rcs_new_parts: Vec<SortedVec<RubiksCubeCompressedX2>> = right_rcs.items
+					.into_par_iter()
+					.chunks(chunk_size)
+					.map(|rcs| {
+						let mut rcs_new: SortedVec<RubiksCubeCompressedX2> = SortedVec::new();
+						for rc in rcs.iter() {
+							for rc_new in rc.to_rc().juxt() {
+								rcs_new.insert(rc_new.to_compressed_x2());
+							}
+						}
+						rcs_new
+					})
+					.collect();
+				right_rcs_new = SortedVec::from_sorted_vecs(right_rcs_new_parts);
+			}
+			right_moves += 1;
+			right_rcs = right_rcs_new;
+
+			if let Some(rc_middle) = left_rcs.intersection_with(&right_rcs) {
+				break rc_middle
+			}
+		};
+
+		// println!("rc_middle:\n{}", rc_middle.to_string1());
+
+		let solution = if left_moves + right_moves == 1 {
+			assert_eq!(right_moves, 0);
+			vec![ALL_MOVES[rc_init.juxt().into_iter().position(|rc| rc == rc_final).unwrap()].clone()]
+		}
+		else {
+			let rc_middle = rc_middle.to_rc();
+			[
+				rc_init.solve(&rc_middle),
+				rc_middle.solve(&rc_final),
+			].concat()
+		};
+		verify::debug_check(self, &solution, other);
+		solution
+	}
+
+	pub fn solve_compressed_x2_unsorted_vec_without_capacity(&self, other: &RubiksCube) -> Vec<Move> {
+		trait Solve { fn solve(&self, other: &Self) -> Vec<Move>; }
+		impl Solve for RubiksCube { fn solve(&self, other: &Self) -> Vec<Move> {
+			self.solve_compressed_x2_unsorted_vec_without_capacity(other)
+		} }
+
+		let rc_init: RubiksCube = self.clone();
+		let rc_final: RubiksCube = other.clone();
+		let mut left_rcs: SortedVec<RubiksCubeCompressedX2> = SortedVec::from_item(rc_init.to_compressed_x2());
+		let mut right_rcs: SortedVec<RubiksCubeCompressedX2> = SortedVec::from_item(rc_final.to_compressed_x2());
+		let mut left_moves: u32 = 0;
+		let mut right_moves: u32 = 0;
+
+		let rc_middle = loop {
+			log_ply(left_moves, right_moves, left_rcs.len(), right_rcs.len());
+
+			let mut left_rcs_new: Vec<RubiksCubeCompressedX2> = Vec::new();
+			if CORES_N == 1 {
+				for rc in left_rcs.items.iter() {
+					for rc_new in rc.to_rc().juxt() {
+						left_rcs_new.push(rc_new.to_compressed_x2());
+					}
+				}
+			}
+			else {
+				let chunk_size: usize = left_rcs.len().div_ceil(CORES_N);
+				let left_rcs_new_parts: Vec<Vec<RubiksCubeCompressedX2>> = left_rcs.items
+					.into_par_iter()
+					.chunks(chunk_size)
+					.map(|rcs| {
+						let mut rcs_new: Vec<RubiksCubeCompressedX2> = Vec::new();
+						for rc in rcs.iter() {
+							for rc_new in rc.to_rc().juxt() {
+								rcs_new.push(rc_new.to_compressed_x2());
+							}
+						}
+						rcs_new
+					})
+					.collect();
+				left_rcs_new = left_rcs_new_parts.concat();
+			}
+			left_moves += 1;
+			left_rcs = SortedVec::from_vec(left_rcs_new);
+			left_rcs.shrink_to_fit();
+
+			log_ply(left_moves, right_moves, left_rcs.len(), right_rcs.len());
+
+			if let Some(rc_middle) = left_rcs.intersection_with(&right_rcs) {
+				break rc_middle
+			}
+
+			let mut right_rcs_new: Vec<RubiksCubeCompressedX2> = Vec::new();
+			if CORES_N == 1 {
+				for rc in right_rcs.items.iter() {
+					for rc_new in rc.to_rc().juxt() {
+						right_rcs_new.push(rc_new.to_compressed_x2());
+					}
+				}
+			}
+			else {
+				let chunk_size: usize = right_rcs.len().div_ceil(CORES_N);
+				let right_rcs_new_parts: Vec<Vec<RubiksCubeCompressedX2>> = right_rcs.items
+					.into_par_iter()
+					.chunks(chunk_size)
+					.map(|rcs| {
+						let mut rcs_new: Vec<RubiksCubeCompressedX2> = Vec::new();
+						for rc in rcs.iter() {
+							for rc_new in rc.to_rc().juxt() {
+								rcs_new.push(rc_new.to_compressed_x2());
+							}
+						}
+						rcs_new
+					})
+					.collect();
+				right_rcs_new = right_rcs_new_parts.concat();
+			}
+			right_moves += 1;
+			right_rcs = SortedVec::from_vec(right_rcs_new);
+			right_rcs.shrink_to_fit();
+
+			if let Some(rc_middle) = left_rcs.intersection_with(&right_rcs) {
+				break rc_middle
+			}
+		};
+
+		// println!("rc_middle:\n{}", rc_middle.to_string1());
+
+		let solution = if left_moves + right_moves == 1 {
+			assert_eq!(right_moves, 0);
+			vec![ALL_MOVES[rc_init.juxt().into_iter().position(|rc| rc == rc_final).unwrap()].clone()]
+		}
+		else {
+			let rc_middle = rc_middle.to_rc();
+			[
+				rc_init.solve(&rc_middle),
+				rc_middle.solve(&rc_final),
+			].concat()
+		};
+		verify::debug_check(self, &solution, other);
+		solution
+	}
+
+	pub fn solve_compressed_x2_unsorted_vec_with_capacity(&self, other: &RubiksCube) -> Vec<Move> {
+		trait Solve { fn solve(&self, other: &Self) -> Vec<Move>; }
+		impl Solve for RubiksCube { fn solve(&self, other: &Self) -> Vec<Move> {
+			self.solve_compressed_x2_unsorted_vec_with_capacity(other)
+		} }
+
+		let rc_init: RubiksCube = self.clone();
+		let rc_final: RubiksCube = other.clone();
+		let mut left_rcs: SortedVec<RubiksCubeCompressedX2> = SortedVec::from_item(rc_init.to_compressed_x2());
+		let mut right_rcs: SortedVec<RubiksCubeCompressedX2> = SortedVec::from_item(rc_final.to_compressed_x2());
+		let mut left_moves: u32 = 0;
+		let mut right_moves: u32 = 0;
+
+		let rc_middle = loop {
+			log_ply(left_moves, right_moves, left_rcs.len(), right_rcs.len());
+
+			/// approximate array size growth rate
+			const GROWTH_RATE: usize = 13;
+
+			let mut left_rcs_new: Vec<RubiksCubeCompressedX2> = Vec::with_capacity(left_rcs.len() * GROWTH_RATE);
+			if CORES_N == 1 {
+				for rc in left_rcs.items.iter() {
+					for rc_new in rc.to_rc().juxt() {
+						left_rcs_new.push(rc_new.to_compressed_x2());
+					}
+				}
+			}
+			else {
+				let chunk_size: usize = left_rcs.len().div_ceil(CORES_N);
+				let left_rcs_new_parts: Vec<Vec<RubiksCubeCompressedX2>> = left_rcs.items
+					.into_par_iter()
+					.chunks(chunk_size)
+					.map(|rcs| {
+						let mut rcs_new: Vec<RubiksCubeCompressedX2> = Vec::with_capacity(chunk_size * GROWTH_RATE);
+						for rc in rcs.iter() {
+							for rc_new in rc.to_rc().juxt() {
+								rcs_new.push(rc_new.to_compressed_x2());
+							}
+						}
+						rcs_new
+					})
+					.collect();
+				left_rcs_new = left_rcs_new_parts.concat();
+			}
+			left_moves += 1;
+			left_rcs = SortedVec::from_vec(left_rcs_new);
+			left_rcs.shrink_to_fit();
+
+			log_ply(left_moves, right_moves, left_rcs.len(), right_rcs.len());
+
+			if let Some(rc_middle) = left_rcs.intersection_with(&right_rcs) {
+				break rc_middle
+			}
+
+			let mut right_rcs_new: Vec<RubiksCubeCompressedX2> = Vec::with_capacity(right_rcs.len() * GROWTH_RATE);
+			if CORES_N == 1 {
+				for rc in right_rcs.items.iter() {
+					for rc_new in rc.to_rc().juxt() {
+						right_rcs_new.push(rc_new.to_compressed_x2());
+					}
+				}
+			}
+			else {
+				let chunk_size: usize = right_rcs.len().div_ceil(CORES_N);
+				let right_rcs_new_parts: Vec<Vec<RubiksCubeCompressedX2>> = right_rcs.items
+					.into_par_iter()
+					.chunks(chunk_size)
+					.map(|rcs| {
+						let mut rcs_new: Vec<RubiksCubeCompressedX2> = Vec::with_capacity(chunk_size * GROWTH_RATE);
+						for rc in rcs.iter() {
+							for rc_new in rc.to_rc().juxt() {
+								rcs_new.push(rc_new.to_compressed_x2());
+							}
+						}
+						rcs_new
+					})
+					.collect();
+				right_rcs_new = right_rcs_new_parts.concat();
+			}
+			right_moves += 1;
+			right_rcs = SortedVec::from_vec(right_rcs_new);
+			right_rcs.shrink_to_fit();
+
+			if let Some(rc_middle) = left_rcs.intersection_with(&right_rcs) {
+				break rc_middle
+			}
+		};
+
+		// println!("rc_middle:\n{}", rc_middle.to_string1());
+
+		let solution = if left_moves + right_moves == 1 {
+			assert_eq!(right_moves, 0);
+			vec![ALL_MOVES[rc_init.juxt().into_iter().position(|rc| rc == rc_final).unwrap()].clone()]
+		}
+		else {
+			let rc_middle = rc_middle.to_rc();
+			[
+				rc_init.solve(&rc_middle),
+				rc_middle.solve(&rc_final),
+			].concat()
+		};
+		verify::debug_check(self, &solution, other);
+		solution
+	}
+
+
+
+	pub fn solve_compressed_x3_sorted_vec(&self, other: &RubiksCube) -> Vec<Move> {
+		trait Solve { fn solve(&self, other: &Self) -> Vec<Move>; }
+		impl Solve for RubiksCube { fn solve(&self, other: &Self) -> Vec<Move> {
+			self.solve_compressed_x3_sorted_vec(other)
+		} }
+
+		let rc_init: RubiksCube = self.clone();
+		let rc_final: RubiksCube = other.clone();
+		let mut left_rcs: SortedVec<RubiksCubeCompressedX3> = SortedVec::from_item(rc_init.to_compressed_x3());
+		let mut right_rcs: SortedVec<RubiksCubeCompressedX3> = SortedVec::from_item(rc_final.to_compressed_x3());
+		let mut left_moves: u32 = 0;
+		let mut right_moves: u32 = 0;
+
+		if let Some(checkpoint) = checkpoint::resume(&rc_init, &rc_final) {
+			Checkpoint { left_rcs, right_rcs, left_moves, right_moves } = checkpoint;
+		}
+
+		let rc_middle = loop {
+			log_ply(left_moves, right_moves, left_rcs.len(), right_rcs.len());
+
+			let mut left_rcs_new: SortedVec<RubiksCubeCompressedX3> = SortedVec::new();
+			if CORES_N == 1 {
+				for rc in left_rcs.items.iter() {
+					for rc_new in rc.to_rc().juxt() {
+						left_rcs_new.insert(rc_new.to_compressed_x3());
+					}
+				}
+			}
+			else {
+				let chunk_size: usize = left_rcs.len().div_ceil(CORES_N);
+				let left_rcs_new_parts: Vec<SortedVec<RubiksCubeCompressedX3>> = left_rcs.items
+					.into_par_iter()
+					.chunks(chunk_size)
+					.map(|rcs| {
+						let mut rcs_new: SortedVec<RubiksCubeCompressedX3> = SortedVec::new();
+						for rc in rcs.iter() {
+							for rc_new in rc.to_rc().juxt() {
+								rcs_new.insert(rc_new.to_compressed_x3());
+							}
+						}
+						rcs_new
+					})
+					.collect();
+				left_rcs_new = SortedVec::from_sorted_vecs(left_rcs_new_parts);
+			}
+			left_moves += 1;
+			left_rcs = left_rcs_new;
+
+			log_ply(left_moves, right_moves, left_rcs.len(), right_rcs.len());
+
+			if let Some(rc_middle) = left_rcs.intersection_with(&right_rcs) {
+				break rc_middle
+			}
+
+			let mut right_rcs_new: SortedVec<RubiksCubeCompressedX3> = SortedVec::new();
+			if CORES_N == 1 {
+				for rc in right_rcs.items.iter() {
+					for rc_new in rc.to_rc().juxt() {
+						right_rcs_new.insert(rc_new.to_compressed_x3());
+					}
+				}
+			}
+			else {
+				let chunk_size: usize = right_rcs.len().div_ceil(CORES_N);
+				let right_rcs_new_parts: Vec<SortedVec<RubiksCubeCompressedX3>> = right_rcs.items
+					.into_par_iter()
+					.chunks(chunk_size)
+					.map(|rcs| {
+						let mut rcs_new: SortedVec<RubiksCubeCompressedX3> = SortedVec::new();
+						for rc in rcs.iter() {
+							for rc_new in rc.to_rc().juxt() {
+								rcs_new.insert(rc_new.to_compressed_x3());
+							}
+						}
+						rcs_new
+					})
+					.collect();
+				right_rcs_new = SortedVec::from_sorted_vecs(right_rcs_new_parts);
+			}
+			right_moves += 1;
+			right_rcs = right_rcs_new;
+
+			if let Some(rc_middle) = left_rcs.intersection_with(&right_rcs) {
+				break rc_middle
+			}
+
+			checkpoint::save(&rc_init, &rc_final, left_moves, right_moves, &left_rcs, &right_rcs);
+		};
+
+		// println!("rc_middle:\n{}", rc_middle.to_string1());
+
+		let solution = if left_moves + right_moves == 1 {
+			assert_eq!(right_moves, 0);
+			vec![ALL_MOVES[rc_init.juxt().into_iter().position(|rc| rc == rc_final).unwrap()].clone()]
+		}
+		else {
+			let rc_middle = rc_middle.to_rc();
+			[
+				rc_init.solve(&rc_middle),
+				rc_middle.solve(&rc_final),
+			].concat()
+		};
+		checkpoint::finish::<RubiksCubeCompressedX3>(&rc_init, &rc_final);
+		verify::debug_check(self, &solution, other);
+		solution
+	}
+
+	pub fn solve_compressed_x3_unsorted_vec_without_capacity(&self, other: &RubiksCube) -> Vec<Move> {
+		trait Solve { fn solve(&self, other: &Self) -> Vec<Move>; }
+		impl Solve for RubiksCube { fn solve(&self, other: &Self) -> Vec<Move> {
+			self.solve_compressed_x3_unsorted_vec_without_capacity(other)
+		} }
+
+		let rc_init: RubiksCube = self.clone();
+		let rc_final: RubiksCube = other.clone();
+		let mut left_rcs: SortedVec<RubiksCubeCompressedX3> = SortedVec::from_item(rc_init.to_compressed_x3());
+		let mut right_rcs: SortedVec<RubiksCubeCompressedX3> = SortedVec::from_item(rc_final.to_compressed_x3());
+		let mut left_moves: u32 = 0;
+		let mut right_moves: u32 = 0;
+
+		if let Some(checkpoint) = checkpoint::resume(&rc_init, &rc_final) {
+			Checkpoint { left_rcs, right_rcs, left_moves, right_moves } = checkpoint;
+		}
+
+		let rc_middle = loop {
+			log_ply(left_moves, right_moves, left_rcs.len(), right_rcs.len());
+
+			let mut left_rcs_new: Vec<RubiksCubeCompressedX3> = Vec::new();
+			if CORES_N == 1 {
+				for rc in left_rcs.items.iter() {
+					for rc_new in rc.to_rc().juxt() {
+						left_rcs_new.push(rc_new.to_compressed_x3());
+					}
+				}
+			}
+			else {
+				let chunk_size: usize = left_rcs.len().div_ceil(CORES_N);
+				let left_rcs_new_parts: Vec<Vec<RubiksCubeCompressedX3>> = left_rcs.items
+					.into_par_iter()
+					.chunks(chunk_size)
+					.map(|rcs| {
+						let mut rcs_new: Vec<RubiksCubeCompressedX3> = Vec::new();
+						for rc in rcs.iter() {
+							for rc_new in rc.to_rc().juxt() {
+								rcs_new.push(rc_new.to_compressed_x3());
+							}
+						}
+						rcs_new
+					})
+					.collect();
+				left_rcs_new = left_rcs_new_parts.concat();
+			}
+			left_moves += 1;
+			left_rcs = SortedVec::from_vec(left_rcs_new);
+			left_rcs.shrink_to_fit();
+
+			log_ply(left_moves, right_moves, left_rcs.len(), right_rcs.len());
+
+			if let Some(rc_middle) = left_rcs.intersection_with(&right_rcs) {
+				break rc_middle
+			}
+
+			let mut right_rcs_new: Vec<RubiksCubeCompressedX3> = Vec::new();
+			if CORES_N == 1 {
+				for rc in right_rcs.items.iter() {
+					for rc_new in rc.to_rc().juxt() {
+						right_rcs_new.push(rc_new.to_compressed_x3());
+					}
+				}
+			}
+			else {
+				let chunk_size: usize = right_rcs.len().div_ceil(CORES_N);
+				let right_rcs_new_parts: Vec<Vec<RubiksCubeCompressedX3>> = right_rcs.items
+					.into_par_iter()
+					.chunks(chunk_size)
+					.map(|rcs| {
+						let mut rcs_new: Vec<RubiksCubeCompressedX3> = Vec::new();
+						for rc in rcs.iter() {
+							for rc_new in rc.to_rc().juxt() {
+								rcs_new.push(rc_new.to_compressed_x3());
+							}
+						}
+						rcs_new
+					})
+					.collect();
+				right_rcs_new = right_rcs_new_parts.concat();
+			}
+			right_moves += 1;
+			right_rcs = SortedVec::from_vec(right_rcs_new);
+			right_rcs.shrink_to_fit();
+
+			if let Some(rc_middle) = left_rcs.intersection_with(&right_rcs) {
+				break rc_middle
+			}
+
+			checkpoint::save(&rc_init, &rc_final, left_moves, right_moves, &left_rcs, &right_rcs);
+		};
+
+		// println!("rc_middle:\n{}", rc_middle.to_string1());
+
+		let solution = if left_moves + right_moves == 1 {
+			assert_eq!(right_moves, 0);
+			vec![ALL_MOVES[rc_init.juxt().into_iter().position(|rc| rc == rc_final).unwrap()].clone()]
+		}
+		else {
+			let rc_middle = rc_middle.to_rc();
+			[
+				rc_init.solve(&rc_middle),
+				rc_middle.solve(&rc_final),
+			].concat()
+		};
+		checkpoint::finish::<RubiksCubeCompressedX3>(&rc_init, &rc_final);
+		verify::debug_check(self, &solution, other);
+		solution
+	}
+
+	pub fn solve_compressed_x3_unsorted_vec_with_capacity(&self, other: &RubiksCube) -> Vec<Move> {
+		trait Solve { fn solve(&self, other: &Self) -> Vec<Move>; }
+		impl Solve for RubiksCube { fn solve(&self, other: &Self) -> Vec<Move> {
+			self.solve_compressed_x3_unsorted_vec_with_capacity(other)
+		} }
+
+		let rc_init: RubiksCube = self.clone();
+		let rc_final: RubiksCube = other.clone();
+		let mut left_rcs: SortedVec<RubiksCubeCompressedX3> = SortedVec::from_item(rc_init.to_compressed_x3());
+		let mut right_rcs: SortedVec<RubiksCubeCompressedX3> = SortedVec::from_item(rc_final.to_compressed_x3());
+		let mut left_moves: u32 = 0;
+		let mut right_moves: u32 = 0;
+
+		if let Some(checkpoint) = checkpoint::resume(&rc_init, &rc_final) {
+			Checkpoint { left_rcs, right_rcs, left_moves, right_moves } = checkpoint;
+		}
+
+		let rc_middle = loop {
+			log_ply(left_moves, right_moves, left_rcs.len(), right_rcs.len());
+
+			/// approximate array size growth rate
+			const GROWTH_RATE: usize = 13;
+
+			let mut left_rcs_new: Vec<RubiksCubeCompressedX3> = Vec::with_capacity(left_rcs.len() * GROWTH_RATE);
+			if CORES_N == 1 {
+				for rc in left_rcs.items.iter() {
+					for rc_new in rc.to_rc().juxt() {
+						left_rcs_new.push(rc_new.to_compressed_x3());
+					}
+				}
+			}
+			else {
+				let chunk_size: usize = left_rcs.len().div_ceil(CORES_N);
+				let left_rcs_new_parts: Vec<Vec<RubiksCubeCompressedX3>> = left_rcs.items
+					.into_par_iter()
+					.chunks(chunk_size)
+					.map(|rcs| {
+						let mut rcs_new: Vec<RubiksCubeCompressedX3> = Vec::with_capacity(chunk_size * GROWTH_RATE);
+						for rc in rcs.iter() {
+							for rc_new in rc.to_rc().juxt() {
+								rcs_new.push(rc_new.to_compressed_x3());
+							}
+						}
+						rcs_new
+					})
+					.collect();
+				left_rcs_new = left_rcs_new_parts.concat();
+			}
+			left_moves += 1;
+			left_rcs = SortedVec::from_vec(left_rcs_new);
+			left_rcs.shrink_to_fit();
+
+			log_ply(left_moves, right_moves, left_rcs.len(), right_rcs.len());
+
+			if let Some(rc_middle) = left_rcs.intersection_with(&right_rcs) {
+				break rc_middle
+			}
+
+			let mut right_rcs_new: Vec<RubiksCubeCompressedX3> = Vec::with_capacity(right_rcs.len() * GROWTH_RATE);
+			if CORES_N == 1 {
+				for rc in right_rcs.items.iter() {
+					for rc_new in rc.to_rc().juxt() {
+						right_rcs_new.push(rc_new.to_compressed_x3());
+					}
+				}
+			}
+			else {
+				let chunk_size: usize = right_rcs.len().div_ceil(CORES_N);
+				let right_rcs_new_parts: Vec<Vec<RubiksCubeCompressedX3>> = right_rcs.items
+					.into_par_iter()
+					.chunks(chunk_size)
+					.map(|rcs| {
+						let mut rcs_new: Vec<RubiksCubeCompressedX3> = Vec::with_capacity(chunk_size * GROWTH_RATE);
+						for rc in rcs.iter() {
+							for rc_new in rc.to_rc().juxt() {
+								rcs_new.push(rc_new.to_compressed_x3());
+							}
+						}
+						rcs_new
+					})
+					.collect();
+				right_rcs_new = right_rcs_new_parts.concat();
+			}
+			right_moves += 1;
+			right_rcs = SortedVec::from_vec(right_rcs_new);
+			right_rcs.shrink_to_fit();
+
+			if let Some(rc_middle) = left_rcs.intersection_with(&right_rcs) {
+				break rc_middle
+			}
+
+			checkpoint::save(&rc_init, &rc_final, left_moves, right_moves, &left_rcs, &right_rcs);
+		};
+
+		// println!("rc_middle:\n{}", rc_middle.to_string1());
+
+		let solution = if left_moves + right_moves == 1 {
+			assert_eq!(right_moves, 0);
+			vec![ALL_MOVES[rc_init.juxt().into_iter().position(|rc| rc == rc_final).unwrap()].clone()]
+		}
+		else {
+			let rc_middle = rc_middle.to_rc();
+			[
+				rc_init.solve(&rc_middle),
+				rc_middle.solve(&rc_final),
+			].concat()
+		};
+		checkpoint::finish::<RubiksCubeCompressedX3>(&rc_init, &rc_final);
+		verify::debug_check(self, &solution, other);
+		solution
+	}
+
+	pub fn solve_compressed_x3_sorted_vec_smart_without_capacity(&self, other: &RubiksCube) -> Vec<Move> {
+		trait Solve { fn solve(&self, other: &Self) -> Vec<Move>; }
+		impl Solve for RubiksCube { fn solve(&self, other: &Self) -> Vec<Move> {
+			self.solve_compressed_x3_sorted_vec_smart_without_capacity(other)
+		} }
+
+		let rc_init: RubiksCube = self.clone();
+		let rc_final: RubiksCube = other.clone();
+		let mut left_rcs: SortedVec<RubiksCubeCompressedX3> = SortedVec::from_item(rc_init.to_compressed_x3());
+		let mut right_rcs: SortedVec<RubiksCubeCompressedX3> = SortedVec::from_item(rc_final.to_compressed_x3());
+		let mut left_moves: u32 = 0;
+		let mut right_moves: u32 = 0;
+
+		if let Some(checkpoint) = checkpoint::resume(&rc_init, &rc_final) {
+			Checkpoint { left_rcs, right_rcs, left_moves, right_moves } = checkpoint;
+		}
+
+		let rc_middle = loop {
+			log_ply(left_moves, right_moves, left_rcs.len(), right_rcs.len());
+
+			left_rcs = if CORES_N == 1 {
+				let mut left_rcs_new: Vec<RubiksCubeCompressedX3> = Vec::new();
+				for rc in left_rcs.items.iter() {
+					for rc_new in rc.to_rc().juxt() {
+						left_rcs_new.push(rc_new.to_compressed_x3());
+					}
+				}
+				SortedVec::from_vec(left_rcs_new)
+			}
+			else {
+				let chunk_size: usize = left_rcs.len().div_ceil(CORES_N);
+				let left_rcs_new_parts: Vec<SortedVec<RubiksCubeCompressedX3>> = left_rcs.items
+					.into_par_iter()
+					.chunks(chunk_size)
+					.map(|rcs| {
+						let mut rcs_new: Vec<RubiksCubeCompressedX3> = Vec::new();
+						for rc in rcs.iter() {
+							for rc_new in rc.to_rc().juxt() {
+								rcs_new.push(rc_new.to_compressed_x3());
+							}
+						}
+						SortedVec::from_vec(rcs_new)
+					})
+					.collect();
+				SortedVec::from_sorted_vecs(left_rcs_new_parts)
+			};
+			left_moves += 1;
+			left_rcs.shrink_to_fit();
+
+			log_ply(left_moves, right_moves, left_rcs.len(), right_rcs.len());
+
+			if let Some(rc_middle) = left_rcs.intersection_with(&right_rcs) {
+				break rc_middle
+			}
+
+			right_rcs = if CORES_N == 1 {
+				let mut right_rcs_new: Vec<RubiksCubeCompressedX3> = Vec::new();
+				for rc in right_rcs.items.iter() {
+					for rc_new in rc.to_rc().juxt() {
+						right_rcs_new.push(rc_new.to_compressed_x3());
+					}
+				}
+				SortedVec::from_vec(right_rcs_new)
+			}
+			else {
+				let chunk_size: usize = right_rcs.len().div_ceil(CORES_N);
+				let right_rcs_new_parts: Vec<SortedVec<RubiksCubeCompressedX3>> = right_rcs.items
+					.into_par_iter()
+					.chunks(chunk_size)
+					.map(|rcs| {
+						let mut rcs_new: Vec<RubiksCubeCompressedX3> = Vec::new();
+						for rc in rcs.iter() {
+							for rc_new in rc.to_rc().juxt() {
+								rcs_new.push(rc_new.to_compressed_x3());
+							}
+						}
+						SortedVec::from_vec(rcs_new)
+					})
+					.collect();
+				SortedVec::from_sorted_vecs(right_rcs_new_parts)
+			};
+			right_moves += 1;
+			right_rcs.shrink_to_fit();
+
+			if let Some(rc_middle) = left_rcs.intersection_with(&right_rcs) {
+				break rc_middle
+			}
+
+			checkpoint::save(&rc_init, &rc_final, left_moves, right_moves, &left_rcs, &right_rcs);
+		};
+
+		// println!("rc_middle:\n{}", rc_middle.to_string1());
+
+		let solution = if left_moves + right_moves == 1 {
+			assert_eq!(right_moves, 0);
+			vec![ALL_MOVES[rc_init.juxt().into_iter().position(|rc| rc == rc_final).unwrap()].clone()]
+		}
+		else {
+			let rc_middle = rc_middle.to_rc();
+			[
+				rc_init.solve(&rc_middle),
+				rc_middle.solve(&rc_final),
+			].concat()
+		};
+		checkpoint::finish::<RubiksCubeCompressedX3>(&rc_init, &rc_final);
+		verify::debug_check(self, &solution, other);
+		solution
+	}
+
+	/// Superseded by `solve_packed_sorted_vec_smart_with_capacity_smart` (the default solver): same search,
+	/// but each move here decompresses to `RubiksCube` and back. Kept as a baseline for `bench`.
+	///
+	/// ```
+	/// # use rubiks_cube_solver_rs::{Move, RubiksCube};
+	/// let mut rc = RubiksCube::new();
+	/// rc.make_moves(&Move::moves_from_notation("F U R'").unwrap());
+	/// let solution = rc.solve_compressed_x3_sorted_vec_smart_with_capacity_smart(&RubiksCube::new());
+	/// assert_eq!(3, solution.len());
+	/// rc.make_moves(&solution);
+	/// assert_eq!(RubiksCube::new(), rc);
+	/// ```
+	pub fn solve_compressed_x3_sorted_vec_smart_with_capacity_smart(&self, other: &RubiksCube) -> Vec<Move> {
+		self.solve_compressed_x3_frontier::<SortedVec<RubiksCubeCompressedX3>>(other)
+	}
+
+	/// ```
+	/// # use rubiks_cube_solver_rs::{Move, RubiksCube};
+	/// let mut rc = RubiksCube::new();
+	/// rc.make_moves(&Move::moves_from_notation("F U R'").unwrap());
+	/// let solution = rc.solve_packed_sorted_vec_smart_with_capacity_smart(&RubiksCube::new());
+	/// assert_eq!(3, solution.len());
+	/// rc.make_moves(&solution);
+	/// assert_eq!(RubiksCube::new(), rc);
+	/// ```
+	pub fn solve_packed_sorted_vec_smart_with_capacity_smart(&self, other: &RubiksCube) -> Vec<Move> {
+		self.solve_packed_frontier::<SortedVec<RubiksCubePacked>>(other)
+	}
+
+	/// Same as `solve_compressed_x3_sorted_vec_smart_with_capacity_smart`, but generic over frontier backend,
+	/// so backends can be compared on the same scrambles (see `frontier::compare`).
+	pub fn solve_compressed_x3_frontier<F: Frontier<RubiksCubeCompressedX3>>(&self, other: &RubiksCube) -> Vec<Move> {
+		self.solve_frontier::<RubiksCubeCompressedX3, F>(
+			other,
+			RubiksCube::to_compressed_x3,
+			|rc| rc.to_rc(),
+			RubiksCubeCompressedX3::juxt,
+			|rc_init, rc_final| rc_init.solve_compressed_x3_frontier::<F>(rc_final),
+		)
+	}
+
+	/// Same as `solve_packed_sorted_vec_smart_with_capacity_smart`, but generic over frontier backend.
+	pub fn solve_packed_frontier<F: Frontier<RubiksCubePacked>>(&self, other: &RubiksCube) -> Vec<Move> {
+		self.solve_frontier::<RubiksCubePacked, F>(
+			other,
+			RubiksCube::to_packed,
+			|rc| rc.to_rc(),
+			RubiksCubePacked::juxt,
+			|rc_init, rc_final| rc_init.solve_packed_frontier::<F>(rc_final),
+		)
+	}
+
+	/// Meet-in-the-middle search with frontiers `F` of items `T`, checkpointed after every ply;
+	/// halves are solved by `solve_half`.
+	fn solve_frontier<T: CheckpointItem + Send + Sync, F: Frontier<T>>(
+		&self,
+		other: &RubiksCube,
+		to_item: impl Fn(&RubiksCube) -> T,
+		to_rc: impl Fn(&T) -> RubiksCube,
+		juxt: impl Fn(&T) -> [T; 18] + Sync + Copy,
+		solve_half: impl Fn(&RubiksCube, &RubiksCube) -> Vec<Move>,
+	) -> Vec<Move> {
+		let rc_init: RubiksCube = self.clone();
+		let rc_final: RubiksCube = other.clone();
+		let mut left_rcs: F = F::from_item(to_item(&rc_init));
+		let mut right_rcs: F = F::from_item(to_item(&rc_final));
+		let mut left_moves: u32 = 0;
+		let mut right_moves: u32 = 0;
+
+		if let Some(checkpoint) = checkpoint::resume::<T>(&rc_init, &rc_final) {
+			left_rcs = F::from_items(checkpoint.left_rcs.items);
+			right_rcs = F::from_items(checkpoint.right_rcs.items);
+			(left_moves, right_moves) = (checkpoint.left_moves, checkpoint.right_moves);
+		}
+
+		let rc_middle = loop {
+			log_ply(left_moves, right_moves, left_rcs.len(), right_rcs.len());
+
+			left_rcs = left_rcs.expand(juxt);
+			left_moves += 1;
+
+			log_ply(left_moves, right_moves, left_rcs.len(), right_rcs.len());
+
+			if let Some(rc_middle) = left_rcs.intersection_with(&right_rcs) {
+				break rc_middle
+			}
+
+			right_rcs = right_rcs.expand(juxt);
+			right_moves += 1;
+
+			if let Some(rc_middle) = left_rcs.intersection_with(&right_rcs) {
+				break rc_middle
+			}
+
+			checkpoint::save(&rc_init, &rc_final, left_moves, right_moves, &left_rcs, &right_rcs);
+		};
+		drop((left_rcs, right_rcs));
+
+		let solution = if left_moves + right_moves == 1 {
+			assert_eq!(right_moves, 0);
+			vec![ALL_MOVES[rc_init.juxt().into_iter().position(|rc| rc == rc_final).unwrap()].clone()]
+		}
+		else {
+			let rc_middle = to_rc(&rc_middle);
+			[
+				solve_half(&rc_init, &rc_middle),
+				solve_half(&rc_middle, &rc_final),
+			].concat()
+		};
+		checkpoint::finish::<T>(&rc_init, &rc_final);
+		verify::debug_check(self, &solution, other);
+		solution
+	}
+
+	/// Solves to `RubiksCube::new`, storing only `canonicalize_symmetry` representatives in frontiers,
+	/// which is possible because solved cube is symmetric and move set is closed under symmetries.
+	///
+	/// ```
+	/// # use rubiks_cube_solver_rs::{Move, RubiksCube};
+	/// let mut rc = RubiksCube::new();
+	/// rc.make_moves(&Move::moves_from_notation("F U R'").unwrap());
+	/// let solution = rc.solve_symmetry_reduced();
+	/// assert_eq!(3, solution.len());
+	/// rc.make_moves(&solution);
+	/// assert_eq!(RubiksCube::new(), rc);
+	/// ```
+	pub fn solve_symmetry_reduced(&self) -> Vec<Move> {
+		let solution = self.solve_symmetry_reduced_to_class(&RubiksCube::new());
+		verify::debug_check(self, &solution, &RubiksCube::new());
+		solution
+	}
+
+	/// Shortest moves from `self` to any cube symmetric to `other`.
+	///
+	/// Frontiers hold only representatives (as previous frontiers aren't kept, up to 48 times less memory),
+	/// so the middle found is a class: `self` reaches some cube of it in `left_moves`, as neighbours of equivalent cubes
+	/// are equivalent. Halves are solved by recursion, as in other solvers, the second one from the cube actually reached.
+	fn solve_symmetry_reduced_to_class(&self, other: &RubiksCube) -> Vec<Move> {
+		fn juxt(rc: &RubiksCubeCompressedX3) -> [RubiksCubeCompressedX3; 18] {
+			rc.to_rc().juxt().map(|rc_new| rc_new.canonicalize_symmetry().to_compressed_x3())
+		}
+
+		let rc_init: RubiksCube = self.clone();
+		let rc_final_class: RubiksCubeCompressedX3 = other.canonicalize_symmetry().to_compressed_x3();
+		let mut left_rcs: SortedVec<RubiksCubeCompressedX3> = SortedVec::from_item(rc_init.canonicalize_symmetry().to_compressed_x3());
+		let mut right_rcs: SortedVec<RubiksCubeCompressedX3> = SortedVec::from_item(rc_final_class);
+		if left_rcs.intersection_with(&right_rcs).is_some() { return vec![] }
+		let mut left_moves: u32 = 0;
+		let mut right_moves: u32 = 0;
+
+		let rc_middle = loop {
+			log_ply(left_moves, right_moves, left_rcs.len(), right_rcs.len());
+
+			left_rcs = left_rcs.expand(juxt);
+			left_moves += 1;
+
+			log_ply(left_moves, right_moves, left_rcs.len(), right_rcs.len());
+
+			if let Some(rc_middle) = left_rcs.intersection_with(&right_rcs) {
+				break rc_middle
+			}
+
+			right_rcs = right_rcs.expand(juxt);
+			right_moves += 1;
+
+			if let Some(rc_middle) = left_rcs.intersection_with(&right_rcs) {
+				break rc_middle
+			}
+		};
+		drop((left_rcs, right_rcs));
+
+		if left_moves + right_moves == 1 {
+			let m = rc_init.juxt().into_iter().position(|rc| rc.canonicalize_symmetry().to_compressed_x3() == rc_final_class).unwrap();
+			return vec![ALL_MOVES[m].clone()]
+		}
+		let solution_left = rc_init.solve_symmetry_reduced_to_class(&rc_middle.to_rc());
+		let mut rc_middle_reached = rc_init.clone();
+		rc_middle_reached.make_moves(&solution_left);
+		[solution_left, rc_middle_reached.solve_symmetry_reduced_to_class(other)].concat()
+	}
+
+	/// Solves to `RubiksCube::new`, expanding only left side: right side is layer `solved_depth` of `solved_side`,
+	/// computed once and shared by all solves (and runs, if `--solved-side-dir` is given).
+	///
+	/// ```
+	/// # use rubiks_cube_solver_rs::{Move, RubiksCube};
+	/// let mut rc = RubiksCube::new();
+	/// rc.make_moves(&Move::moves_from_notation("F U R'").unwrap());
+	/// let solution = rc.solve_with_solved_side(2);
+	/// assert_eq!(3, solution.len());
+	/// rc.make_moves(&solution);
+	/// assert_eq!(RubiksCube::new(), rc);
+	/// ```
+	pub fn solve_with_solved_side(&self, solved_depth: u32) -> Vec<Move> {
+		let solved_side = solved_side::get(solved_depth);
+		let rc_init: RubiksCube = self.clone();
+		if let Some(k) = solved_side.distance(&rc_init.to_compressed_x3()) {
+			let solution = solved_side.path_to_solved(&rc_init, k);
+			verify::debug_check(self, &solution, &RubiksCube::new());
+			return solution
+		}
+		let right_rcs: &[RubiksCubeCompressedX3] = solved_side.layer(solved_depth);
+		let mut left_layers: Vec<SortedVec<RubiksCubeCompressedX3>> = vec![SortedVec::from_item(rc_init.to_compressed_x3())];
+
+		let rc_middle = loop {
+			log_ply(left_layers.len() as u32 - 1, solved_depth, left_layers.last().unwrap().len(), right_rcs.len());
+
+			left_layers.push(left_layers.last().unwrap().clone().expand(RubiksCubeCompressedX3::juxt));
+
+			if let Some(rc_middle) = left_layers.last().unwrap().intersection_with_items(right_rcs) {
+				break rc_middle.to_rc()
+			}
+		};
+
+		let mut path_left: Vec<RubiksCube> = solved_side::walk_down(&left_layers, rc_middle.clone());
+		path_left.reverse();
+		let solution = [
+			solved_side::moves_along(&path_left),
+			solved_side.path_to_solved(&rc_middle, solved_depth),
+		].concat();
+		verify::debug_check(self, &solution, &RubiksCube::new());
+		solution
+	}
+
+	/// Frontier items are built from cubies, so both cubes must be solvable.
+	///
+	/// # Panics
+	/// If `self` or `other` isn't solvable (check with `CubieCube::from_rc`).
+	///
+	/// ```
+	/// # use rubiks_cube_solver_rs::{Move, RubiksCube};
+	/// let mut rc = RubiksCube::new();
+	/// rc.make_moves(&Move::moves_from_notation("F U R'").unwrap());
+	/// let solution = rc.solve_coord_sorted_vec_smart_with_capacity_smart(&RubiksCube::new());
+	/// assert_eq!(3, solution.len());
+	/// rc.make_moves(&solution);
+	/// assert_eq!(RubiksCube::new(), rc);
+	/// ```
+	pub fn solve_coord_sorted_vec_smart_with_capacity_smart(&self, other: &RubiksCube) -> Vec<Move> {
+		self.solve_frontier::<CoordCube, SortedVec<CoordCube>>(
+			other,
+			RubiksCube::to_coord,
+			|rc| rc.to_rc(),
+			CoordCube::juxt,
+			|rc_init, rc_final| rc_init.solve_coord_sorted_vec_smart_with_capacity_smart(rc_final),
+		)
+	}
+
+	/// Frontier items are built from cubies, so both cubes must be solvable.
+	///
+	/// # Panics
+	/// If `self` or `other` isn't solvable (check with `CubieCube::from_rc`).
+	///
+	/// ```
+	/// # use rubiks_cube_solver_rs::{Move, RubiksCube};
+	/// let mut rc = RubiksCube::new();
+	/// rc.make_moves(&Move::moves_from_notation("F U R'").unwrap());
+	/// let solution = rc.solve_ranked_sorted_vec_smart_with_capacity_smart(&RubiksCube::new());
+	/// assert_eq!(3, solution.len());
+	/// rc.make_moves(&solution);
+	/// assert_eq!(RubiksCube::new(), rc);
+	/// ```
+	pub fn solve_ranked_sorted_vec_smart_with_capacity_smart(&self, other: &RubiksCube) -> Vec<Move> {
+		self.solve_frontier::<u128, SortedVec<u128>>(
+			other,
+			RubiksCube::to_ranked,
+			|rank| CubieCube::unrank(*rank).to_rc(),
+			|rank| CubieCube::unrank(*rank).juxt().map(|cc| cc.rank()),
+			|rc_init, rc_final| rc_init.solve_ranked_sorted_vec_smart_with_capacity_smart(rc_final),
+		)
+	}
+
+
+
+	pub fn to_compressed_x2(&self) -> RubiksCubeCompressedX2 {
+		RubiksCubeCompressedX2::from_rc(self.clone())
+	}
+
+	pub fn to_compressed_x3(&self) -> RubiksCubeCompressedX3 {
+		RubiksCubeCompressedX3::from_rc(self.clone())
+	}
+
+	pub fn to_packed(&self) -> RubiksCubePacked {
+		RubiksCubePacked::from_rc(self.clone())
+	}
+
+	/// # Panics
+	/// If cube isn't solvable.
+	pub fn to_ranked(&self) -> u128 {
+		CubieCube::from_rc(self).expect("cube must be solvable").rank()
+	}
+
+	/// # Panics
+	/// If cube isn't solvable.
+	pub fn to_coord(&self) -> CoordCube {
+		CoordCube::from_rc(self)
+	}
+
+	pub fn juxt(&self) -> [RubiksCube; 18] {
+		[
+			{ let mut rc = self.clone(); rc.front(); rc },
+			{ let mut rc = self.clone(); rc.front_s(); rc },
+			{ let mut rc = self.clone(); rc.back(); rc },
+			{ let mut rc = self.clone(); rc.back_s(); rc },
+			{ let mut rc = self.clone(); rc.left(); rc },
+			{ let mut rc = self.clone(); rc.left_s(); rc },
+			{ let mut rc = self.clone(); rc.right(); rc },
+			{ let mut rc = self.clone(); rc.right_s(); rc },
+			{ let mut rc = self.clone(); rc.top(); rc },
+			{ let mut rc = self.clone(); rc.top_s(); rc },
+			{ let mut rc = self.clone(); rc.bottom(); rc },
+			{ let mut rc = self.clone(); rc.bottom_s(); rc },
+			{ let mut rc = self.clone(); rc.x(); rc },
+			{ let mut rc = self.clone(); rc.x_s(); rc },
+			{ let mut rc = self.clone(); rc.y(); rc },
+			{ let mut rc = self.clone(); rc.y_s(); rc },
+			{ let mut rc = self.clone(); rc.z(); rc },
+			{ let mut rc = self.clone(); rc.z_s(); rc },
+		]
+	}
+
+	pub fn front(&mut self) {
+		self.pieces.rotate4(12, 14, 38, 36);
+		self.pieces.rotate4(13, 26, 37, 24);
+		self.pieces.rotate4(6, 15, 47, 35);
+		self.pieces.rotate4(7, 27, 46, 23);
+		self.pieces.rotate4(8, 39, 45, 11);
+	}
+	pub fn front_s(&mut self) {
+		self.pieces.rotate4(12, 36, 38, 14);
+		self.pieces.rotate4(13, 24, 37, 26);
+		self.pieces.rotate4(6, 35, 47, 15);
+		self.pieces.rotate4(7, 23, 46, 27);
+		self.pieces.rotate4(8, 11, 45, 39);
+	}
+	pub fn back(&mut self) {
+		self.pieces.rotate4(18, 20, 44, 42);
+		self.pieces.rotate4(19, 32, 43, 30);
+		self.pieces.rotate4(0, 33, 53, 17);
+		self.pieces.rotate4(1, 21, 52, 29);
+		self.pieces.rotate4(2, 9, 51, 41);
+	}
+	pub fn back_s(&mut self) {
+		self.pieces.rotate4(18, 42, 44, 20);
+		self.pieces.rotate4(19, 30, 43, 32);
+		self.pieces.rotate4(0, 17, 53, 33);
+		self.pieces.rotate4(1, 29, 52, 21);
+		self.pieces.rotate4(2, 41, 51, 9);
+	}
+	pub fn left(&mut self) {
+		self.pieces.rotate4(9, 11, 35, 33);
+		self.pieces.rotate4(10, 23, 34, 21);
+		self.pieces.rotate4(0, 12, 45, 44);
+		self.pieces.rotate4(3, 24, 48, 32);
+		self.pieces.rotate4(6, 36, 51, 20);
+	}
+	pub fn left_s(&mut self) {
+		self.pieces.rotate4(9, 33, 35, 11);
+		self.pieces.rotate4(10, 21, 34, 23);
+		self.pieces.rotate4(0, 44, 45, 12);
+		self.pieces.rotate4(3, 32, 48, 24);
+		self.pieces.rotate4(6, 20, 51, 36);
+	}
+	pub fn right(&mut self) {
+		self.pieces.rotate4(15, 17, 41, 39);
+		self.pieces.rotate4(16, 29, 40, 27);
+		self.pieces.rotate4(8, 18, 53, 38);
+		self.pieces.rotate4(5, 30, 50, 26);
+		self.pieces.rotate4(2, 42, 47, 14);
+	}
+	pub fn right_s(&mut self) {
+		self.pieces.rotate4(15, 39, 41, 17);
+		self.pieces.rotate4(16, 27, 40, 29);
+		self.pieces.rotate4(8, 38, 53, 18);
+		self.pieces.rotate4(5, 26, 50, 30);
+		self.pieces.rotate4(2, 14, 47, 42);
+	}
+	pub fn top(&mut self) {
+		self.pieces.rotate4(0, 2, 8, 6);
+		self.pieces.rotate4(1, 5, 7, 3);
+		self.pieces.rotate4(12, 9, 18, 15);
+		self.pieces.rotate4(13, 10, 19, 16);
+		self.pieces.rotate4(14, 11, 20, 17);
+	}
+	pub fn top_s(&mut self) {
+		self.pieces.rotate4(0, 6, 8, 2);
+		self.pieces.rotate4(1, 3, 7, 5);
+		self.pieces.rotate4(12, 15, 18, 9);
+		self.pieces.rotate4(13, 16, 19, 10);
+		self.pieces.rotate4(14, 17, 20, 11);
+	}
+	pub fn bottom(&mut self) {
+		self.pieces.rotate4(45, 47, 53, 51);
+		self.pieces.rotate4(46, 50, 52, 48);
+		self.pieces.rotate4(36, 39, 42, 33);
+		self.pieces.rotate4(37, 40, 43, 34);
+		self.pieces.rotate4(38, 41, 44, 35);
+	}
+	pub fn bottom_s(&mut self) {
+		self.pieces.rotate4(45, 51, 53, 47);
+		self.pieces.rotate4(46, 48, 52, 50);
+		self.pieces.rotate4(36, 33, 42, 39);
+		self.pieces.rotate4(37, 34, 43, 40);
+		self.pieces.rotate4(38, 35, 44, 41);
+	}
+	pub fn x(&mut self) {
+		self.left_s();
+		self.right();
+	}
+	pub fn x_s(&mut self) {
+		self.left();
+		self.right_s();
+	}
+	pub fn y(&mut self) {
+		self.top();
+		self.bottom_s();
+	}
+	pub fn y_s(&mut self) {
+		self.top_s();
+		self.bottom();
+	}
+	pub fn z(&mut self) {
+		self.front();
+		self.back_s();
+	}
+	pub fn z_s(&mut self) {
+		self.front_s();
+		self.back();
+	}
+
+	pub fn to_string1(&self) -> String {
+		let [_00, _01, _02, _03, _04, _05, _06, _07, _08, _09, _10, _11, _12, _13, _14, _15, _16, _17, _18, _19, _20, _21, _22, _23, _24, _25, _26, _27, _28, _29, _30, _31, _32, _33, _34, _35, _36, _37, _38, _39, _40, _41, _42, _43, _44, _45, _46, _47, _48, _49, _50, _51, _52, _53] = self.pieces;
+		[
+			format!("      {_00:?} {_01:?} {_02:?}\n"),
+			format!("      {_03:?} {_04:?} {_05:?}\n"),
+			format!("      {_06:?} {_07:?} {_08:?}\n"),
+			format!("{_09:?} {_10:?} {_11:?} {_12:?} {_13:?} {_14:?} {_15:?} {_16:?} {_17:?} {_18:?} {_19:?} {_20:?}\n"),
+			format!("{_21:?} {_22:?} {_23:?} {_24:?} {_25:?} {_26:?} {_27:?} {_28:?} {_29:?} {_30:?} {_31:?} {_32:?}\n"),
+			format!("{_33:?} {_34:?} {_35:?} {_36:?} {_37:?} {_38:?} {_39:?} {_40:?} {_41:?} {_42:?} {_43:?} {_44:?}\n"),
+			format!("      {_45:?} {_46:?} {_47:?}\n"),
+			format!("      {_48:?} {_49:?} {_50:?}\n"),
+			format!("      {_51:?} {_52:?} {_53:?}\n"),
+		].concat()
+	}
+}
+
+
+
+
+
+impl Default for RubiksCube {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ColorPair {
+	value: u8
+}
+impl ColorPair {
+	pub fn from_colors_array(colors: [Color; 2]) -> Self {
+		Self::from_colors(colors[0], colors[1])
+	}
+	pub fn from_colors(c1: Color, c2: Color) -> Self {
+		Self { value: (c1.to_u8() << 4) | c2.to_u8() }
+	}
+	pub fn to_colors_array(self) -> [Color; 2] {
+		self.to_colors().into()
+	}
+	pub fn to_colors(self) -> (Color, Color) {
+		let c1 = Color::from_u8((self.value & 0b_1111_0000_u8) >> 4);
+		let c2 = Color::from_u8(self.value & 0b_0000_1111_u8);
+		(c1, c2)
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RubiksCubeCompressedX2 {
+	pieces: [ColorPair; 6*9/2]
+}
+impl RubiksCubeCompressedX2 {
+	pub fn from_rc(rc: RubiksCube) -> Self {
+		let mut self_ = Self { pieces: [ColorPair { value: 0 }; 27] };
+		for (i, rc_p1_p2) in rc.pieces.chunks(2).enumerate() {
+			let [p1, p2] = rc_p1_p2 else { unreachable!() };
+			let color_pair = ColorPair::from_colors(*p1, *p2);
+			self_.pieces[i] = color_pair;
+		}
+		self_
+	}
+
+	pub fn to_rc(&self) -> RubiksCube {
+		let mut rc = RubiksCube::new();
+		for (i, color_pair) in self.pieces.iter().enumerate() {
+			let (c1, c2) = color_pair.to_colors();
+			rc.pieces[2*i+0] = c1;
+			rc.pieces[2*i+1] = c2;
+		}
+		rc
+	}
+}
+
+
+
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct ColorTriple {
+	value: u8
+}
+impl ColorTriple {
+	pub fn from_colors_array(colors: [Color; 3]) -> Self {
+		Self::from_colors(colors[0], colors[1], colors[2])
+	}
+	pub fn from_colors(c1: Color, c2: Color, c3: Color) -> Self {
+		Self { value: c1.to_u8() * 36 + c2.to_u8() * 6 + c3.to_u8() }
+	}
+	pub fn to_colors_array(self) -> [Color; 3] {
+		self.to_colors().into()
+	}
+	pub fn to_colors(self) -> (Color, Color, Color) {
+		let c3 = Color::from_u8(self.value % 6);
+		let c2 = Color::from_u8((self.value / 6) % 6);
+		let c1 = Color::from_u8((self.value / 36) % 6);
+		(c1, c2, c3)
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct RubiksCubeCompressedX3 {
+	pieces: [ColorTriple; 6*9/3]
+}
+impl RubiksCubeCompressedX3 {
+	pub fn from_rc(rc: RubiksCube) -> Self {
+		let mut self_ = Self { pieces: [ColorTriple { value: 0 }; 18] };
+		for (i, rc_p1_p2_p3) in rc.pieces.chunks(3).enumerate() {
+			let [p1, p2, p3] = rc_p1_p2_p3 else { unreachable!() };
+			let color_triple = ColorTriple::from_colors(*p1, *p2, *p3);
+			self_.pieces[i] = color_triple;
+		}
+		self_
+	}
+
+	pub fn to_rc(&self) -> RubiksCube {
+		let mut rc = RubiksCube::new();
+		for (i, color_triple) in self.pieces.iter().enumerate() {
+			let (c1, c2, c3) = color_triple.to_colors();
+			rc.pieces[3*i+0] = c1;
+			rc.pieces[3*i+1] = c2;
+			rc.pieces[3*i+2] = c3;
+		}
+		rc
+	}
+
+	pub fn juxt(&self) -> [Self; 18] {
+		self.to_rc().juxt().map(|rc_new| rc_new.to_compressed_x3())
+	}
+
+	pub fn to_bytes(self) -> [u8; 18] {
+		self.pieces.map(|color_triple| color_triple.value)
+	}
+
+	pub fn from_bytes(bytes: [u8; 18]) -> Self {
+		Self { pieces: bytes.map(|value| ColorTriple { value }) }
+	}
+}
+
+
+
+
+
+// trait ExtArrayRotate {
+// 	fn rotate<const N: usize>(&mut self, indices: [u8; N]);
+// }
+// impl<T: Copy, const L: usize> ExtArrayRotate for [T; L] {
+// 	// #[inline] // TODO: test
+// 	fn rotate<const N: usize>(&mut self, indices: [u8; N]) {
+// 		let tmp = self[indices[indices.len()-1] as usize];
+// 		// dbg!(tmp);
+// 		for i in (0..indices.len()-1).rev() {
+// 			let index_l = indices[i+1] as usize;
+// 			let index_r = indices[i] as usize;
+// 			// dbg!(index_l, index_r);
+// 			self[index_l] = self[index_r];
+// 			// dbg!(&self);
+// 		}
+// 		self[indices[0] as usize] = tmp;
+// 	}
+// }
+
+trait ExtArrayRotate4 {
+	fn rotate4(&mut self, i1: usize, i2: usize, i3: usize, i4: usize);
+}
+impl<T: Copy, const L: usize> ExtArrayRotate4 for [T; L] {
+	// #[inline] // TODO: test
+	fn rotate4(&mut self, i1: usize, i2: usize, i3: usize, i4: usize) {
+		let temp = self[i4];
+		self[i4] = self[i3];
+		self[i3] = self[i2];
+		self[i2] = self[i1];
+		self[i1] = temp;
+	}
+}
+
+
+
+#[derive(Debug, Clone)]
+pub struct SortedVec<T: Clone + PartialOrd + Ord> {
+	items: Vec<T>,
+}
+impl<T: Clone + PartialOrd + Ord> SortedVec<T> {
+	pub fn new() -> Self {
+		Self { items: vec![] }
+	}
+
+	pub fn from_item(item: T) -> Self {
+		Self { items: vec![item] }
+	}
+
+	pub fn len(&self) -> usize {
+		self.items.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.items.is_empty()
+	}
+
+	pub fn shrink_to_fit(&mut self) {
+		self.items.shrink_to_fit();
+	}
+
+	pub fn insert(&mut self, item: T) {
+		// dbg!(self.index_of(&item));
+		if let Err(index) = self.index_of(&item) {
+			self.items.insert(index, item);
+		}
+	}
+
+	/// returns `Ok(index where it is)` or `Err(index before which it should be)`.
+	pub fn index_of(&self, target: &T) -> Result<usize, usize> {
+		let mut l = 0;
+		let mut r = self.len();
+		while l < r {
+			let m = l + (r - l) / 2;
+			match self.items[m].cmp(&target) {
+				Ordering::Equal   => return Ok(m),
+				Ordering::Less    => { l = m + 1 }
+				Ordering::Greater => { r = m }
+			}
+		}
+		debug_assert_eq!(l, r);
+		Err(l)
+	}
+
+	pub fn intersection_with(&self, other: &Self) -> Option<T> {
+		self.intersection_with_items(&other.items)
+	}
+
+	/// Same as `intersection_with`, but `other` can be any sorted slice (e.g. memory mapped).
+	pub fn intersection_with_items(&self, other: &[T]) -> Option<T> {
+		let mut index_l = 0;
+		let mut index_r = 0;
+		while index_l < self.len() && index_r < other.len() {
+			match self.items[index_l].cmp(&other[index_r]) {
+				Ordering::Equal => return Some(self.items[index_l].clone()),
+				Ordering::Less    => { index_l += 1; }
+				Ordering::Greater => { index_r += 1; }
+			}
+		}
+		None
+	}
+}
+impl<T: Clone + PartialOrd + Ord> Default for SortedVec<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+impl<T: Clone + PartialOrd + Ord> AsRef<[T]> for SortedVec<T> {
+	fn as_ref(&self) -> &[T] {
+		&self.items
+	}
+}
+impl<T: Clone + PartialOrd + Ord + Send + Sync> SortedVec<T> {
+	pub fn from_vec(mut items: Vec<T>) -> Self {
+		items.par_sort_unstable();
+		items.dedup();
+		Self { items }
+	}
+
+	// fn from_vecs(mut items_vec: Vec<Vec<T>>) -> Self {
+	// 	for items in items_vec.iter_mut() {
+	// 		items_vec.sort();
+	// 		items_vec.dedup();
+	// 	}
+	// 	Self::from_sorted_vecs(items_vec)
+	// }
+
+	pub fn from_array<const N: usize>(items: [T; N]) -> Self {
+		let items = items.to_vec();
+		Self::from_vec(items)
+	}
+
+	/// Merges in parallel: splits value range into `CORES_N` parts by splitter keys
+	/// (evenly spaced items of the largest vec), and merges each part on its own thread.
+	///
+	/// Parts are merged straight into their own ranges of one output vec (sized for all items),
+	/// then moved together over the gaps left by duplicates, so items are held at most twice.
+	pub fn from_sorted_vecs(sorted_vecs: Vec<SortedVec<T>>) -> Self {
+		let Some(largest) = sorted_vecs.iter().max_by_key(|sv| sv.len()).filter(|sv| !sv.is_empty()) else { return Self::new() };
+		let mut splitters: Vec<&T> = (1..CORES_N)
+			.map(|k| &largest.items[k * largest.len() / CORES_N])
+			.collect();
+		splitters.dedup();
+
+		// `part_ranges[k][i]` is range of `sorted_vecs[i]` in part `k`.
+		let part_ranges: Vec<Vec<std::ops::Range<usize>>> = (0..=splitters.len())
+			.map(|k| {
+				sorted_vecs.iter()
+					.map(|sv| {
+						let l = if k == 0 { 0 } else { sv.items.partition_point(|item| item < splitters[k-1]) };
+						let r = if k == splitters.len() { sv.len() } else { sv.items.partition_point(|item| item < splitters[k]) };
+						l..r
+					})
+					.collect()
+			})
+			.collect();
+		let part_lens: Vec<usize> = part_ranges.iter().map(|ranges| ranges.iter().map(|range| range.len()).sum()).collect();
+
+		let mut items: Vec<T> = Vec::with_capacity(part_lens.iter().sum());
+		let mut outputs = vec![];
+		let mut spare = items.spare_capacity_mut();
+		for &part_len in &part_lens {
+			let (output, rest) = std::mem::take(&mut spare).split_at_mut(part_len);
+			outputs.push(output);
+			spare = rest;
+		}
+		let written: Vec<usize> = outputs.into_par_iter()
+			.zip(part_ranges)
+			.map(|(output, ranges)| {
+				let slices = sorted_vecs.iter().zip(ranges).map(|(sv, range)| &sv.items[range]).collect();
+				Self::merge_into(slices, output)
+			})
+			.collect();
+
+		let mut len = 0;
+		let mut part_start = 0;
+		for (part_len, written) in part_lens.into_iter().zip(written) {
+			// SAFETY: `merge_into` initialized the first `written` items of the part, which starts at `part_start >= len`,
+			// and `len` items before are initialized parts moved already (`copy` allows overlapping ranges).
+			unsafe {
+				let ptr = items.as_mut_ptr();
+				std::ptr::copy(ptr.add(part_start), ptr.add(len), written);
+			}
+			len += written;
+			part_start += part_len;
+		}
+		// SAFETY: first `len` items are initialized, see above.
+		unsafe { items.set_len(len) };
+		SortedVec { items }
+	}
+
+	/// Merges sorted slices into `output` (long enough for all their items), removing duplicates,
+	/// returns number of items written.
+	fn merge_into(slices: Vec<&[T]>, output: &mut [MaybeUninit<T>]) -> usize {
+		let mut heap = BinaryHeap::new();
+
+		// Keep track of iterators for each slice
+		let mut iters: Vec<_> = slices.into_iter()
+			.map(|slice| slice.iter())
+			.collect();
+
+		// Initialize the heap with the first element from each iterator
+		for (i, iter) in iters.iter_mut().enumerate() {
+			if let Some(value) = iter.next() {
+				heap.push(Reverse((value, i)));
+			}
+		}
+
+		let mut written = 0;
+		let mut last: Option<&T> = None;
+		while let Some(Reverse((value, i))) = heap.pop() {
+			if last != Some(value) {
+				output[written].write(value.clone());
+				written += 1;
+				last = Some(value);
+			}
+			if let Some(next) = iters[i].next() {
+				heap.push(Reverse((next, i)));
+			}
+		}
+
+		written
+	}
+}
+
+
+
+// trait ExtVecIntersectionWith<T> {
+// 	fn intersection_with(&self, other: &Self) -> Option<T>;
+// }
+// impl<T: Clone + Ord> ExtVecIntersectionWith<T> for Vec<T> {
+// 	fn intersection_with(&self, other: &Self) -> Option<T> {
+// 		SortedVec::from_vec(self.clone()).intersection_with(&SortedVec::from_vec(other.clone()))
+// 	}
+// }
+
+
+
+trait ExtResultCollapse<T> {
+	fn collapse(self) -> T;
+}
+impl<T> ExtResultCollapse<T> for Result<T, T> {
+	fn collapse(self) -> T {
+		match self {
+			Ok(v) => v,
+			Err(e) => e,
+		}
+	}
+}
+
+
+
+#[cfg(test)]
+mod rubiks_cube {
+	use super::*;
+	mod solve_uncompressed {
+		use super::*;
+		mod moves {
+			use super::*;
+			mod _1 {
+				use super::*;
+				#[test]
+				fn front() {
+					let mut rc = RubiksCube::new();
+					rc.front_s();
+					assert_eq!(
+						vec![Move::Front],
+						rc.solve_uncompressed_sorted_vec(&RubiksCube::new())
+					)
+				}
+
+			}
+		}
+	}
+	mod solve_packed {
+		use super::*;
+		#[test]
+		fn moves_4() {
+			let mut rc = RubiksCube::new();
+			rc.make_moves(&[Move::Front, Move::Top, Move::RightS, Move::Y]);
+			let solution = rc.solve_packed_sorted_vec_smart_with_capacity_smart(&RubiksCube::new());
+			assert!(solution.len() <= 4);
+			rc.make_moves(&solution);
+			assert_eq!(RubiksCube::new(), rc)
+		}
+	}
+	mod solve_ranked {
+		use super::*;
+		#[test]
+		fn moves_4() {
+			let mut rc = RubiksCube::new();
+			rc.make_moves(&[Move::Front, Move::Top, Move::RightS, Move::Y]);
+			let solution = rc.solve_ranked_sorted_vec_smart_with_capacity_smart(&RubiksCube::new());
+			assert!(solution.len() <= 4);
+			rc.make_moves(&solution);
+			assert_eq!(RubiksCube::new(), rc)
+		}
+	}
+	mod solve_symmetry_reduced {
+		use super::*;
+		#[test]
+		fn moves_5() {
+			let mut rc = RubiksCube::new();
+			rc.make_moves(&[Move::Front, Move::Top, Move::RightS, Move::Y, Move::Left]);
+			let solution = rc.solve_symmetry_reduced();
+			assert!(solution.len() <= 5);
+			assert_eq!(rc.solve_compressed_x3_sorted_vec(&RubiksCube::new()).len(), solution.len());
+			rc.make_moves(&solution);
+			assert_eq!(RubiksCube::new(), rc)
+		}
+	}
+	mod solve_with_solved_side {
+		use super::*;
+		#[test]
+		fn moves_5() {
+			let mut rc = RubiksCube::new();
+			rc.make_moves(&[Move::Front, Move::Top, Move::RightS, Move::Y, Move::Left]);
+			let solution = rc.solve_with_solved_side(2);
+			assert!(solution.len() <= 5);
+			rc.make_moves(&solution);
+			assert_eq!(RubiksCube::new(), rc)
+		}
+	}
+	mod solve_coord {
+		use super::*;
+		#[test]
+		fn moves_4() {
+			let mut rc = RubiksCube::new();
+			rc.make_moves(&[Move::Front, Move::Top, Move::RightS, Move::Y]);
+			let solution = rc.solve_coord_sorted_vec_smart_with_capacity_smart(&RubiksCube::new());
+			assert!(solution.len() <= 4);
+			rc.make_moves(&solution);
+			assert_eq!(RubiksCube::new(), rc)
+		}
+	}
+	mod moves {
+		use super::*;
+		use Color::*;
+		#[test]
+		fn front_solved() {
+			let mut rc = RubiksCube::new();
+			rc.front();
+			assert_eq!(
+				RubiksCube::from_pieces([
+					       Y,Y,Y,
+					       Y,Y,Y,
+					       B,B,B,
+					B,B,W, R,R,R, Y,G,G, O,O,O,
+					B,B,W, R,R,R, Y,G,G, O,O,O,
+					B,B,W, R,R,R, Y,G,G, O,O,O,
+					       G,G,G,
+					       W,W,W,
+					       W,W,W,
+				]),
+				rc
+			)
+		}
+	}
+	#[test]
+	fn shuffle() {
+		use rand::{rngs::StdRng, SeedableRng};
+		let rng = &mut StdRng::seed_from_u64(0);
+		for _ in 0..100 {
+			let mut rc = RubiksCube::new();
+			let moves = rc.shuffle(30, rng);
+			for window in moves.windows(2) {
+				let mut rc_pair = RubiksCube::new();
+				rc_pair.make_moves(window);
+				assert!(rc_pair != RubiksCube::new() && !RubiksCube::new().juxt().contains(&rc_pair), "{window:?}");
+			}
+			for window in moves.windows(3) {
+				assert!(window[0] != window[1] || window[1] != window[2]);
+			}
+			rc.make_moves(&Move::inverse_moves(&moves));
+			assert_eq!(RubiksCube::new(), rc);
+		}
+		// same axis pairs, that aren't shorter as a single move, are allowed
+		assert_eq!(3, REDUNDANT_AFTER[Move::Front as usize].iter().filter(|&&redundant| redundant).count());
+		assert!(!REDUNDANT_AFTER[Move::Front as usize][Move::Back as usize]);
+		assert!(!REDUNDANT_AFTER[Move::Front as usize][Move::Z as usize]);
+		assert!(REDUNDANT_AFTER[Move::Front as usize][Move::BackS as usize]);
+	}
+	#[test]
+	fn parse_scramble() {
+		use rand::{rngs::StdRng, SeedableRng};
+		let rng = &mut StdRng::seed_from_u64(0);
+		for _ in 0..20 {
+			let mut rc = RubiksCube::new();
+			let moves = rc.shuffle(20, rng);
+			assert_eq!(Ok(rc.clone()), RubiksCube::parse_scramble(&Move::moves_to_notation(&moves)));
+			assert_eq!(Ok(rc.clone()), RubiksCube::parse_scramble(&rc.to_facelets()));
+		}
+		let mut rc = RubiksCube::new();
+		rc.make_moves(&[Move::Front, Move::Front, Move::TopS]);
+		assert_eq!(Ok(rc), RubiksCube::parse_scramble("F2 U'"));
+		assert!(RubiksCube::parse_scramble("F2 Q").is_err());
+		assert!(RubiksCube::parse_scramble(&"W".repeat(54)).is_err());
+	}
+	#[test]
+	fn shuffle_seeded() {
+		use rand::{rngs::StdRng, SeedableRng};
+		let scrambles = |seed| {
+			let rng = &mut StdRng::seed_from_u64(seed);
+			let rcs: Vec<RubiksCube> = (0..10).map(|_| RubiksCube::new_shuffled(20, rng)).collect();
+			(rcs, RubiksCube::new_random_state(rng))
+		};
+		assert_eq!(scrambles(42), scrambles(42));
+		assert_ne!(scrambles(42), scrambles(43));
+	}
+	mod to_string1 {
+		use super::*;
+		#[test]
+		fn new() {
+			let expected = [
+				"      Y Y Y\n",
+				"      Y Y Y\n",
+				"      Y Y Y\n",
+				"B B B R R R G G G O O O\n",
+				"B B B R R R G G G O O O\n",
+				"B B B R R R G G G O O O\n",
+				"      W W W\n",
+				"      W W W\n",
+				"      W W W\n",
+			].concat();
+			let actual = RubiksCube::new().to_string1();
+			println!("expected:\n{expected}");
+			println!("actual:\n{actual}");
+			assert_eq!(expected, actual)
+		}
+	}
+}
+
+#[test]
+fn rotate4() {
+	//              0    1    2    3    4    5    6    7
+	let mut arr = ['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h'];
+	arr.rotate4(1, 4, 5, 6);
+	assert_eq!(
+		['a', 'g', 'c', 'd', 'b', 'e', 'f', 'h'],
+		arr
+	);
+}
+
+#[cfg(test)]
+mod sorted_vec {
+	use super::*;
+	#[test]
+	fn from_sorted_vecs() {
+		use rand::{rngs::StdRng, SeedableRng};
+		let rng = &mut StdRng::seed_from_u64(0);
+		for len in [0, 1, 10, 1000, 100_000] {
+			let vecs: Vec<Vec<u32>> = (0..CORES_N + 3)
+				.map(|_| (0..rng.random_range(0..=len)).map(|_| rng.random_range(0..len as u32 + 1)).collect())
+				.collect();
+			let expected = SortedVec::from_vec(vecs.concat());
+			let actual = SortedVec::from_sorted_vecs(vecs.iter().cloned().map(SortedVec::from_vec).collect());
+			assert_eq!(expected.items, actual.items);
+
+			// items that aren't `Copy`, with heap memory of their own
+			let strings = |vec: &Vec<u32>| SortedVec::from_vec(vec.iter().map(|i| i.to_string()).collect());
+			let expected = strings(&vecs.concat());
+			let actual = SortedVec::from_sorted_vecs(vecs.iter().map(strings).collect());
+			assert_eq!(expected.items, actual.items);
+		}
+	}
+}
+
+/// Property tests of move algebra, encodings and solvers, over random move sequences.
+#[cfg(test)]
+mod properties {
+	use super::*;
+	use proptest::prelude::*;
+
+	fn moves() -> impl Strategy<Value = Vec<Move>> {
+		prop::collection::vec(0..18usize, 0..30).prop_map(|indices| indices.into_iter().map(|i| ALL_MOVES[i].clone()).collect())
+	}
+
+	fn scrambled() -> impl Strategy<Value = RubiksCube> {
+		moves().prop_map(|moves| {
+			let mut rc = RubiksCube::new();
+			rc.make_moves(&moves);
+			rc
+		})
+	}
+
+	/// Any stickers, not necessarily a reachable state.
+	fn pieces() -> impl Strategy<Value = [Color; 54]> {
+		prop::collection::vec(0..6u8, 54).prop_map(|values| std::array::from_fn(|i| Color::from_u8(values[i])))
+	}
+
+	/// Pair of cubes differing in a few stickers, so that comparisons don't end at the first sticker.
+	fn pieces_pair() -> impl Strategy<Value = ([Color; 54], [Color; 54])> {
+		(pieces(), prop::collection::vec((0..54usize, 0..6u8), 0..4)).prop_map(|(pieces_a, changes)| {
+			let mut pieces_b = pieces_a;
+			for (i, value) in changes {
+				pieces_b[i] = Color::from_u8(value);
+			}
+			(pieces_a, pieces_b)
+		})
+	}
+
+	proptest! {
+		#[test]
+		fn move_then_inverse(rc in scrambled(), m in 0..18usize) {
+			let mut rc_moved = rc.clone();
+			rc_moved.make_move(ALL_MOVES[m].clone());
+			rc_moved.make_move(ALL_MOVES[m].inverse());
+			prop_assert_eq!(rc, rc_moved);
+		}
+
+		#[test]
+		fn quarter_turn_order_4(rc in scrambled(), m in 0..18usize) {
+			let mut rc_moved = rc.clone();
+			for i in 1..=4 {
+				rc_moved.make_move(ALL_MOVES[m].clone());
+				prop_assert_eq!(i == 4, rc == rc_moved);
+			}
+		}
+
+		#[test]
+		fn moves_then_inverse_moves(rc in scrambled(), moves in moves()) {
+			let mut rc_moved = rc.clone();
+			rc_moved.make_moves(&moves);
+			rc_moved.make_moves(&Move::inverse_moves(&moves));
+			prop_assert_eq!(rc, rc_moved);
+		}
+
+		#[test]
+		fn slice_moves_are_face_turns(rc in scrambled()) {
+			use Move::*;
+			for (move_, face_turns) in [(X, [LeftS, Right]), (Xs, [Left, RightS]), (Y, [Top, BottomS]), (Ys, [TopS, Bottom]), (Z, [Front, BackS]), (Zs, [FrontS, Back])] {
+				let mut rc_moved = rc.clone();
+				rc_moved.make_move(move_);
+				let mut rc_turned = rc.clone();
+				rc_turned.make_moves(&face_turns);
+				prop_assert_eq!(rc_turned, rc_moved);
+			}
+		}
+
+		#[test]
+		fn compressed_roundtrip(pieces in pieces()) {
+			let rc = RubiksCube::from_pieces(pieces);
+			prop_assert_eq!(&rc, &RubiksCubeCompressedX2::from_rc(rc.clone()).to_rc());
+			prop_assert_eq!(&rc, &RubiksCubeCompressedX3::from_rc(rc.clone()).to_rc());
+			let x3 = rc.to_compressed_x3();
+			prop_assert_eq!(x3, RubiksCubeCompressedX3::from_bytes(x3.to_bytes()));
+		}
+
+		/// Solvers sort and dedup compressed cubes, so their order must be that of the cubes.
+		#[test]
+		fn compressed_ord((pieces_a, pieces_b) in pieces_pair()) {
+			let (rc_a, rc_b) = (RubiksCube::from_pieces(pieces_a), RubiksCube::from_pieces(pieces_b));
+			let ordering = rc_a.cmp(&rc_b);
+			prop_assert_eq!(ordering, rc_a.to_compressed_x2().cmp(&rc_b.to_compressed_x2()));
+			prop_assert_eq!(ordering, rc_a.to_compressed_x3().cmp(&rc_b.to_compressed_x3()));
+		}
+	}
+
+	proptest! {
+		#![proptest_config(ProptestConfig::with_cases(8))]
+		#[test]
+		fn solvers_solve(seed: u64, depth in 1..=4u32) {
+			use rand::{rngs::StdRng, SeedableRng};
+			let rc = RubiksCube::new_shuffled(depth, &mut StdRng::seed_from_u64(seed));
+			VERBOSE.store(false, atomic::Ordering::Relaxed);
+			// same as `with-solved-side-5`, but its table takes a minute to build in debug builds
+			for (solver_name, solver) in SOLVERS.into_iter().filter(|(solver_name, _)| *solver_name != "with-solved-side-6") {
+				let mut rc_solved = rc.clone();
+				rc_solved.make_moves(&solver(&rc));
+				prop_assert_eq!(RubiksCube::new(), rc_solved, "{} returned wrong solution", solver_name);
+			}
+		}
+	}
+}
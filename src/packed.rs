@@ -0,0 +1,154 @@
+//! `RubiksCube` packed as 3 bits per sticker, with moves applied directly to the packed bits.
+//!
+//! Centers never move (`x`, `y` and `z` turn two opposite faces), so only 48 non-center stickers are stored.
+//! 48 * 3 = 144 bits don't fit into `u128`, so faces U, L, F, R, B go into `hi` and face D goes into `lo`.
+//! Each face is stored as a ring of 8 stickers, clockwise from the top left one,
+//! so turning a face moves its own stickers by the same shift.
+
+use std::sync::LazyLock;
+
+use crate::{Color, Move, RubiksCube, ALL_MOVES};
+
+
+
+/// Indices of stored stickers in `RubiksCube::pieces`, in storage order.
+const STICKERS: [usize; 48] = {
+	/// (index of top left sticker, row stride) of faces U, L, F, R, B, D
+	const FACES: [(usize, usize); 6] = [(0, 3), (9, 12), (12, 12), (15, 12), (18, 12), (45, 3)];
+	/// (row, column) of face stickers, clockwise from the top left one
+	const RING: [(usize, usize); 8] = [(0, 0), (0, 1), (0, 2), (1, 2), (2, 2), (2, 1), (2, 0), (1, 0)];
+	let mut stickers = [0; 48];
+	let mut i = 0;
+	while i < 48 {
+		let (index_top_left, stride) = FACES[i / 8];
+		let (row, column) = RING[i % 8];
+		stickers[i] = index_top_left + row * stride + column;
+		i += 1;
+	}
+	stickers
+};
+
+/// Returns (word, bit offset) of sticker stored at `position`.
+const fn bit_position(position: usize) -> (usize, usize) {
+	if position < 40 { (0, 3 * position) } else { (1, 3 * (position - 40)) }
+}
+
+/// Stickers of one word, that are moved to another (or the same) word by the same shift.
+#[derive(Debug)]
+struct Shuffle {
+	word_src: usize,
+	word_dst: usize,
+	shift: i32,
+	mask: u128,
+}
+
+/// `SHUFFLES[move_ as usize]` applies `move_`, derived from `rotate4`s of corresponding `RubiksCube` method.
+static SHUFFLES: LazyLock<[Vec<Shuffle>; 18]> = LazyLock::new(|| {
+	let mut position_of = [None; 54];
+	for (position, &index) in STICKERS.iter().enumerate() {
+		position_of[index] = Some(position);
+	}
+	ALL_MOVES.map(|move_| {
+		let permutation = move_.sticker_permutation();
+		let mut shuffles: Vec<Shuffle> = vec![];
+		for (position_dst, &index_dst) in STICKERS.iter().enumerate() {
+			let position_src = position_of[permutation[index_dst]].expect("centers are never moved");
+			let (word_src, bit_src) = bit_position(position_src);
+			let (word_dst, bit_dst) = bit_position(position_dst);
+			let shift = bit_dst as i32 - bit_src as i32;
+			let mask = 0b111 << bit_src;
+			match shuffles.iter_mut().find(|s| (s.word_src, s.word_dst, s.shift) == (word_src, word_dst, shift)) {
+				Some(shuffle) => { shuffle.mask |= mask }
+				None => { shuffles.push(Shuffle { word_src, word_dst, shift, mask }) }
+			}
+		}
+		shuffles
+	})
+});
+
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, packed(4))]
+pub struct RubiksCubePacked {
+	hi: u128,
+	lo: u32,
+}
+impl RubiksCubePacked {
+	pub fn from_rc(rc: RubiksCube) -> Self {
+		let mut words = [0_u128; 2];
+		for (position, &index) in STICKERS.iter().enumerate() {
+			let (word, bit) = bit_position(position);
+			words[word] |= (rc.pieces[index].to_u8() as u128) << bit;
+		}
+		Self { hi: words[0], lo: words[1] as u32 }
+	}
+
+	/// Centers are taken from `RubiksCube::new`.
+	pub fn to_rc(self) -> RubiksCube {
+		let words = [self.hi, self.lo as u128];
+		let mut rc = RubiksCube::new();
+		for (position, &index) in STICKERS.iter().enumerate() {
+			let (word, bit) = bit_position(position);
+			rc.pieces[index] = Color::from_u8(((words[word] >> bit) & 0b111) as u8);
+		}
+		rc
+	}
+
+	pub fn to_bytes(self) -> [u8; 20] {
+		let mut bytes = [0; 20];
+		bytes[..16].copy_from_slice(&{ self.hi }.to_le_bytes());
+		bytes[16..].copy_from_slice(&{ self.lo }.to_le_bytes());
+		bytes
+	}
+
+	pub fn from_bytes(bytes: [u8; 20]) -> Self {
+		Self {
+			hi: u128::from_le_bytes(bytes[..16].try_into().unwrap()),
+			lo: u32::from_le_bytes(bytes[16..].try_into().unwrap()),
+		}
+	}
+
+	pub fn make_move(&mut self, move_: &Move) {
+		let words = [self.hi, self.lo as u128];
+		let mut words_new = [0_u128; 2];
+		for &Shuffle { word_src, word_dst, shift, mask } in &SHUFFLES[move_.clone() as usize] {
+			let bits = words[word_src] & mask;
+			words_new[word_dst] |= if shift >= 0 { bits << shift } else { bits >> -shift };
+		}
+		*self = Self { hi: words_new[0], lo: words_new[1] as u32 };
+	}
+
+	pub fn juxt(&self) -> [Self; 18] {
+		ALL_MOVES.each_ref().map(|move_| {
+			let mut rc = *self;
+			rc.make_move(move_);
+			rc
+		})
+	}
+}
+
+
+
+#[test]
+fn to_rc_from_rc() {
+	use rand::{rngs::StdRng, SeedableRng};
+	let rng = &mut StdRng::seed_from_u64(0);
+	for _ in 0..100 {
+		let rc = RubiksCube::new_shuffled(30, rng);
+		assert_eq!(rc, RubiksCubePacked::from_rc(rc.clone()).to_rc());
+	}
+}
+
+#[test]
+fn moves() {
+	use rand::{rngs::StdRng, SeedableRng};
+	let rng = &mut StdRng::seed_from_u64(0);
+	for _ in 0..100 {
+		let rc = RubiksCube::new_shuffled(30, rng);
+		let rc_packed = RubiksCubePacked::from_rc(rc.clone());
+		for (rc_new, rc_packed_new) in rc.juxt().into_iter().zip(rc_packed.juxt()) {
+			assert_eq!(rc_new, rc_packed_new.to_rc());
+		}
+	}
+}
@@ -0,0 +1,121 @@
+//! Cube states as permutations of the 54 sticker positions, rather than colorings of them.
+//!
+//! Unlike colorings, permutations compose: a long sequence is composed once and then applied
+//! to any cube in a single pass, and algorithms can be multiplied and inverted as states.
+//! Permutation of each move comes from `make_move` (its `rotate4` cycles), see `Move::sticker_permutation`.
+
+use std::sync::LazyLock;
+
+use crate::{Move, RubiksCube, ALL_MOVES};
+
+
+
+/// `MOVE_PERMUTATIONS[move_ as usize]` is permutation of `move_`.
+static MOVE_PERMUTATIONS: LazyLock<[Permutation54; 18]> = LazyLock::new(|| {
+	ALL_MOVES.map(|move_| Permutation54::from_indices(move_.sticker_permutation()).unwrap())
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Permutation54 {
+	/// `indices[i]` is position, which sticker at position `i` comes from.
+	indices: [u8; 54],
+}
+impl Permutation54 {
+	pub const IDENTITY: Self = {
+		let mut indices = [0; 54];
+		let mut i = 0;
+		while i < 54 {
+			indices[i] = i as u8;
+			i += 1;
+		}
+		Self { indices }
+	};
+
+	/// `indices` as in `Move::sticker_permutation`, fails if some position is missing.
+	pub fn from_indices(indices: [usize; 54]) -> Result<Self, String> {
+		let mut seen = [false; 54];
+		for &index in &indices {
+			if index >= 54 || std::mem::replace(&mut seen[index], true) {
+				return Err(format!("not a permutation: index {index} out of range or repeated"));
+			}
+		}
+		Ok(Self { indices: indices.map(|index| index as u8) })
+	}
+
+	pub fn indices(&self) -> [usize; 54] {
+		self.indices.map(|index| index as usize)
+	}
+
+	pub fn from_move(move_: &Move) -> Self {
+		MOVE_PERMUTATIONS[move_.clone() as usize]
+	}
+
+	pub fn from_moves(moves: &[Move]) -> Self {
+		moves.iter().fold(Self::IDENTITY, |permutation, move_| permutation.compose(&Self::from_move(move_)))
+	}
+
+	/// Permutation of `self` then `other`.
+	pub fn compose(&self, other: &Self) -> Self {
+		Self { indices: std::array::from_fn(|i| self.indices[other.indices[i] as usize]) }
+	}
+
+	pub fn inverse(&self) -> Self {
+		let mut indices = [0; 54];
+		for (i, &index) in self.indices.iter().enumerate() {
+			indices[index as usize] = i as u8;
+		}
+		Self { indices }
+	}
+
+	/// `self` composed `n` times (by squaring).
+	pub fn pow(&self, mut n: u64) -> Self {
+		let (mut result, mut square) = (Self::IDENTITY, *self);
+		while n > 0 {
+			if n % 2 == 1 {
+				result = result.compose(&square);
+			}
+			square = square.compose(&square);
+			n /= 2;
+		}
+		result
+	}
+
+	/// Cube after moves of this permutation are made on `rc`.
+	pub fn apply(&self, rc: &RubiksCube) -> RubiksCube {
+		RubiksCube::from_pieces(self.indices.map(|index| rc.pieces()[index as usize]))
+	}
+}
+impl Default for Permutation54 {
+	fn default() -> Self {
+		Self::IDENTITY
+	}
+}
+
+
+
+#[test]
+fn permutations() {
+	use rand::{rngs::StdRng, SeedableRng};
+	let rng = &mut StdRng::seed_from_u64(0);
+	let moves = RubiksCube::new().shuffle(100, rng);
+	let permutation = Permutation54::from_moves(&moves);
+	for _ in 0..10 {
+		let rc = RubiksCube::new_shuffled(20, rng);
+		let mut rc_moved = rc.clone();
+		rc_moved.make_moves(&moves);
+		assert_eq!(rc_moved, permutation.apply(&rc));
+		assert_eq!(rc, permutation.inverse().apply(&rc_moved));
+	}
+	assert_eq!(Permutation54::from_moves(&Move::inverse_moves(&moves)), permutation.inverse());
+	assert_eq!(Permutation54::IDENTITY, permutation.compose(&permutation.inverse()));
+
+	let (a, b) = moves.split_at(37);
+	assert_eq!(permutation, Permutation54::from_moves(a).compose(&Permutation54::from_moves(b)));
+	assert_eq!(Permutation54::from_moves(&[moves.as_slice(); 5].concat()), permutation.pow(5));
+	assert_eq!(Permutation54::IDENTITY, Permutation54::from_move(&Move::Front).pow(4));
+	assert_ne!(Permutation54::IDENTITY, Permutation54::from_move(&Move::Front).pow(2));
+
+	let mut indices = Permutation54::IDENTITY.indices();
+	indices[3] = 4;
+	assert!(Permutation54::from_indices(indices).is_err());
+}
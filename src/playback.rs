@@ -0,0 +1,203 @@
+//! Step-by-step playback of a solution: cube after each move, with the move just made highlighted.
+//!
+//! `Playback` holds the states, `run` (`tui` feature) plays them interactively in the terminal:
+//! `→`/`l`/`space` next, `←`/`h` previous, `Home`/`End` first/last, digits then `Enter` jump to step,
+//! `a` toggles autoplay, `+`/`-` change its speed, `q`/`Esc` quit.
+//! Without `tui` feature, `play` prints all steps one after another.
+
+use std::sync::atomic;
+
+use crate::{render::{self, ColorMode, RenderOptions}, solver_by_name, Move, RubiksCube, VERBOSE};
+
+
+
+#[derive(Debug, Clone)]
+pub struct Playback {
+	moves: Vec<Move>,
+	/// `states[i]` is the cube after first `i` moves.
+	states: Vec<RubiksCube>,
+	step: usize,
+}
+impl Playback {
+	pub fn new(rc: &RubiksCube, moves: Vec<Move>) -> Self {
+		let mut states = vec![rc.clone()];
+		let mut rc = rc.clone();
+		for move_ in moves.iter() {
+			rc.make_move(move_.clone());
+			states.push(rc.clone());
+		}
+		Self { moves, states, step: 0 }
+	}
+
+	/// number of moves made so far.
+	pub fn step(&self) -> usize {
+		self.step
+	}
+
+	pub fn steps_n(&self) -> usize {
+		self.moves.len()
+	}
+
+	pub fn current(&self) -> &RubiksCube {
+		&self.states[self.step]
+	}
+
+	/// move that led to `current`.
+	pub fn move_last(&self) -> Option<&Move> {
+		self.step.checked_sub(1).map(|i| &self.moves[i])
+	}
+
+	/// returns `false` if already at the end.
+	pub fn forward(&mut self) -> bool {
+		self.jump(self.step + 1)
+	}
+
+	/// returns `false` if already at the beginning.
+	pub fn back(&mut self) -> bool {
+		self.step > 0 && self.jump(self.step - 1)
+	}
+
+	/// returns `false` if `step` is out of range (and stays where it was).
+	pub fn jump(&mut self, step: usize) -> bool {
+		if step > self.steps_n() { return false }
+		self.step = step;
+		true
+	}
+
+	/// Status line, moves with the last one made highlighted, and the cube.
+	pub fn frame(&self, options: &RenderOptions) -> String {
+		let moves: Vec<String> = self.moves.iter().enumerate()
+			.map(|(i, move_)| {
+				let notation = move_.to_notation();
+				match (i + 1 == self.step, options.color_mode) {
+					(false, _) => notation.to_string(),
+					(true, ColorMode::Plain) => format!("[{notation}]"),
+					(true, _) => format!("\x1b[7m{notation}\x1b[0m"),
+				}
+			})
+			.collect();
+		let move_last = self.move_last().map_or("-", |move_| move_.to_notation());
+		format!(
+			"step {}/{}, last move: {move_last}\n{}\n\n{}",
+			self.step, self.steps_n(), moves.join(" "), render::render(self.current(), options),
+		)
+	}
+}
+
+
+
+#[cfg(feature = "tui")]
+pub fn run(playback: &mut Playback, autoplay_interval: std::time::Duration) -> std::io::Result<()> {
+	use std::{io::{self, Write}, time::{Duration, Instant}};
+
+	use crossterm::{
+		cursor,
+		event::{self, Event, KeyCode, KeyEventKind},
+		queue,
+		terminal::{self, ClearType},
+	};
+
+	let mut stdout = io::stdout();
+	let options = RenderOptions::detect();
+	let _screen = render::RawScreen::enter()?;
+
+	let mut autoplay = false;
+	let mut interval = autoplay_interval;
+	let mut jump_to = String::new();
+	let mut deadline = Instant::now() + interval;
+	loop {
+		queue!(stdout, terminal::Clear(ClearType::All), cursor::MoveTo(0, 0))?;
+		let frame = playback.frame(&options);
+		write!(stdout, "{}", frame.replace('\n', "\r\n"))?;
+		write!(
+			stdout,
+			"\r\nautoplay: {} ({} ms), jump to: {jump_to}\r\n←/→ step, Home/End, digits+Enter jump, a autoplay, +/- speed, q quit",
+			if autoplay { "on" } else { "off" }, interval.as_millis(),
+		)?;
+		stdout.flush()?;
+
+		let timeout = if autoplay { deadline.saturating_duration_since(Instant::now()) } else { Duration::from_secs(3600) };
+		if !event::poll(timeout)? {
+			if !playback.forward() { autoplay = false }
+			deadline = Instant::now() + interval;
+			continue
+		}
+		let Event::Key(key) = event::read()? else { continue };
+		if key.kind != KeyEventKind::Press { continue }
+		match key.code {
+			KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+			KeyCode::Right | KeyCode::Char('l' | ' ') => { playback.forward(); }
+			KeyCode::Left | KeyCode::Char('h') => { playback.back(); }
+			KeyCode::Home => { playback.jump(0); }
+			KeyCode::End => { playback.jump(playback.steps_n()); }
+			KeyCode::Char('a') => {
+				autoplay = !autoplay;
+				deadline = Instant::now() + interval;
+			}
+			KeyCode::Char('+') => { interval = (interval / 2).max(Duration::from_millis(50)) }
+			KeyCode::Char('-') => { interval = (interval * 2).min(Duration::from_secs(10)) }
+			KeyCode::Char(digit @ '0'..='9') => jump_to.push(digit),
+			KeyCode::Backspace => { jump_to.pop(); }
+			KeyCode::Enter => {
+				if let Ok(step) = jump_to.parse() {
+					playback.jump(step);
+				}
+				jump_to.clear();
+			}
+			_ => {}
+		}
+	}
+}
+
+
+
+/// `play` command: solves cube given by `scramble` (notation or facelets, as in `batch`) and plays the solution.
+#[doc(hidden)]
+pub fn play(scramble: &str, solver_name: &str, autoplay_interval: std::time::Duration) {
+	let solver = solver_by_name(solver_name).unwrap_or_else(|| panic!("unknown solver: {solver_name}"));
+	let rc = RubiksCube::parse_scramble(scramble).unwrap_or_else(|e| panic!("invalid scramble: {e}"));
+	VERBOSE.store(false, atomic::Ordering::Relaxed);
+	let solution = if rc == RubiksCube::new() { vec![] } else { solver(&rc) };
+	let mut playback = Playback::new(&rc, solution);
+	#[cfg(feature = "tui")]
+	run(&mut playback, autoplay_interval).expect("terminal error");
+	#[cfg(not(feature = "tui"))]
+	{
+		let _ = autoplay_interval;
+		let options = RenderOptions::detect();
+		loop {
+			println!("{}", playback.frame(&options));
+			if !playback.forward() { break }
+		}
+	}
+}
+
+
+
+#[test]
+fn playback() {
+	use rand::{rngs::StdRng, SeedableRng};
+	let rng = &mut StdRng::seed_from_u64(0);
+	let mut rc = RubiksCube::new();
+	let moves = rc.shuffle(10, rng);
+	let mut playback = Playback::new(&RubiksCube::new(), moves.clone());
+	assert!(!playback.back());
+	assert_eq!(None, playback.move_last());
+	for (i, move_) in moves.iter().enumerate() {
+		assert!(playback.forward());
+		assert_eq!(i + 1, playback.step());
+		assert_eq!(Some(move_), playback.move_last());
+	}
+	assert!(!playback.forward());
+	assert_eq!(&rc, playback.current());
+	assert!(playback.back());
+	assert!(playback.jump(0));
+	assert_eq!(&RubiksCube::new(), playback.current());
+	assert!(!playback.jump(11));
+	assert_eq!(0, playback.step());
+
+	playback.jump(3);
+	let frame = playback.frame(&RenderOptions::default());
+	assert!(frame.starts_with(&format!("step 3/10, last move: {}\n", moves[2].to_notation())));
+	assert!(frame.contains(&format!("[{}]", moves[2].to_notation())));
+}
@@ -0,0 +1,190 @@
+//! Terminal rendering of the unfolded net (same layout as `RubiksCube::to_string1`),
+//! with stickers as ANSI background color blocks, or as letters when colors aren't available.
+
+use std::{env, io::IsTerminal};
+
+use crate::{Color, RubiksCube};
+
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorMode {
+	/// 24 bit colors (`ESC[48;2;R;G;Bm`).
+	TrueColor,
+	/// 256 color palette (`ESC[48;5;Nm`).
+	Ansi256,
+	/// letters of colors, without escape codes.
+	Plain,
+}
+impl ColorMode {
+	/// `Plain` if stdout isn't a terminal or `NO_COLOR` is set,
+	/// `TrueColor` if terminal announces it in `COLORTERM`, `Ansi256` otherwise.
+	pub fn detect() -> Self {
+		if !std::io::stdout().is_terminal() || env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()) {
+			return ColorMode::Plain
+		}
+		match env::var("COLORTERM").as_deref() {
+			Ok("truecolor" | "24bit") => ColorMode::TrueColor,
+			_ => ColorMode::Ansi256,
+		}
+	}
+}
+
+pub(crate) fn rgb(color: Color) -> (u8, u8, u8) {
+	match color {
+		Color::W => (255, 255, 255),
+		Color::Y => (255, 213, 0),
+		Color::O => (255, 88, 0),
+		Color::R => (196, 30, 58),
+		Color::G => (0, 158, 96),
+		Color::B => (0, 81, 186),
+	}
+}
+
+fn ansi256(color: Color) -> u8 {
+	match color {
+		Color::W => 231,
+		Color::Y => 220,
+		Color::O => 208,
+		Color::R => 160,
+		Color::G => 34,
+		Color::B => 25,
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenderOptions {
+	/// in columns.
+	pub sticker_width: usize,
+	/// in lines.
+	pub sticker_height: usize,
+	/// columns between neighbouring stickers.
+	pub gap: usize,
+	pub color_mode: ColorMode,
+}
+impl Default for RenderOptions {
+	fn default() -> Self {
+		Self { sticker_width: 2, sticker_height: 1, gap: 1, color_mode: ColorMode::Plain }
+	}
+}
+impl RenderOptions {
+	/// Default sizes with `ColorMode::detect`.
+	pub fn detect() -> Self {
+		Self { color_mode: ColorMode::detect(), ..Self::default() }
+	}
+
+	fn sticker(&self, color: Color) -> String {
+		self.sticker_labeled(color, "")
+	}
+
+	/// Sticker with `label` (e.g. a cursor) written over it, in black, or after the letter in `Plain`.
+	pub(crate) fn sticker_labeled(&self, color: Color, label: &str) -> String {
+		let w = self.sticker_width;
+		match self.color_mode {
+			ColorMode::TrueColor => {
+				let (r, g, b) = rgb(color);
+				format!("\x1b[48;2;{r};{g};{b}m\x1b[30m{label:^w$}\x1b[0m")
+			}
+			ColorMode::Ansi256 => format!("\x1b[48;5;{}m\x1b[30m{label:^w$}\x1b[0m", ansi256(color)),
+			ColorMode::Plain => format!("{:<w$}", format!("{}{label}", color.to_char())),
+		}
+	}
+}
+
+
+
+/// Index into `pieces` of sticker in `row` (0..9) and `column` (0..12) of the net, if there is one.
+pub(crate) fn net_index(row: usize, column: usize) -> Option<usize> {
+	match (row, column) {
+		(0..3, 3..6) => Some(row * 3 + column - 3),
+		(3..6, 0..12) => Some(9 + (row - 3) * 12 + column),
+		(6..9, 3..6) => Some(45 + (row - 6) * 3 + column - 3),
+		_ => None,
+	}
+}
+
+/// Lines of the net, each of the same visible width (padded with spaces), without line breaks.
+fn net_lines(rc: &RubiksCube, options: &RenderOptions) -> Vec<String> {
+	let blank = " ".repeat(options.sticker_width);
+	let gap = " ".repeat(options.gap);
+	let mut lines = vec![];
+	for row in 0..9 {
+		let line = (0..12)
+			.map(|column| match net_index(row, column) {
+				Some(index) => options.sticker(rc.pieces()[index]),
+				None => blank.clone(),
+			})
+			.collect::<Vec<_>>()
+			.join(&gap);
+		for _ in 0..options.sticker_height {
+			lines.push(line.clone());
+		}
+	}
+	lines
+}
+
+pub fn render(rc: &RubiksCube, options: &RenderOptions) -> String {
+	net_lines(rc, options).into_iter().map(|line| line.trim_end().to_string() + "\n").collect()
+}
+
+/// Nets of `rcs` next to each other (e.g. before and after a solve), separated by `separator` columns.
+pub fn render_side_by_side(rcs: &[&RubiksCube], separator: usize, options: &RenderOptions) -> String {
+	let nets: Vec<Vec<String>> = rcs.iter().map(|rc| net_lines(rc, options)).collect();
+	let separator = " ".repeat(separator);
+	(0..9 * options.sticker_height)
+		.map(|i| {
+			let line = nets.iter().map(|lines| lines[i].as_str()).collect::<Vec<_>>().join(&separator);
+			line.trim_end().to_string() + "\n"
+		})
+		.collect()
+}
+
+
+
+/// Raw mode and alternate screen with hidden cursor, for interactive commands (`tui` feature).
+/// Terminal is restored on drop, so also when the interactive loop returns early with an error.
+#[cfg(feature = "tui")]
+pub(crate) struct RawScreen(());
+#[cfg(feature = "tui")]
+impl RawScreen {
+	pub(crate) fn enter() -> std::io::Result<Self> {
+		crossterm::terminal::enable_raw_mode()?;
+		let screen = Self(());
+		crossterm::execute!(std::io::stdout(), crossterm::terminal::EnterAlternateScreen, crossterm::cursor::Hide)?;
+		Ok(screen)
+	}
+}
+#[cfg(feature = "tui")]
+impl Drop for RawScreen {
+	fn drop(&mut self) {
+		let _ = crossterm::execute!(std::io::stdout(), crossterm::cursor::Show, crossterm::terminal::LeaveAlternateScreen);
+		let _ = crossterm::terminal::disable_raw_mode();
+	}
+}
+
+
+
+#[test]
+fn render_net() {
+	use crate::Move;
+	use rand::{rngs::StdRng, SeedableRng};
+	let rng = &mut StdRng::seed_from_u64(0);
+	let mut rc = RubiksCube::new();
+	rc.shuffle(20, rng);
+	let options_plain = RenderOptions { sticker_width: 1, ..RenderOptions::default() };
+	let expected: String = rc.to_string1().lines().map(|line| line.trim_end().to_string() + "\n").collect();
+	assert_eq!(expected, render(&rc, &options_plain));
+
+	let options = RenderOptions { sticker_width: 3, sticker_height: 2, gap: 0, color_mode: ColorMode::TrueColor };
+	let rendered = render(&rc, &options);
+	assert_eq!(18, rendered.lines().count());
+	assert_eq!(54 * 2, rendered.matches("\x1b[48;2;").count());
+	assert!(!rendered.contains(['W', 'Y', 'O', 'R', 'G', 'B']));
+
+	let mut rc_moved = rc.clone();
+	rc_moved.make_move(Move::Front);
+	let side_by_side = render_side_by_side(&[&rc, &rc_moved], 4, &options_plain);
+	for ((line, line_left), line_right) in side_by_side.lines().zip(render(&rc, &options_plain).lines()).zip(render(&rc_moved, &options_plain).lines()) {
+		assert_eq!(format!("{line_left:<23}    {line_right}").trim_end(), line);
+	}
+}
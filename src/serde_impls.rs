@@ -0,0 +1,101 @@
+//! `serde` support (`serde` feature): `Color` as its letter, `Move` as its notation,
+//! `RubiksCube` (and compressed types) as facelet string, also accepted as array of colors.
+
+use std::fmt;
+
+use serde::{
+	de::{self, SeqAccess, Visitor},
+	Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{Color, Move, RubiksCube, RubiksCubeCompressedX2, RubiksCubeCompressedX3, RubiksCubePacked};
+
+
+
+impl Serialize for Color {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_char(self.to_char())
+	}
+}
+impl<'de> Deserialize<'de> for Color {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let c = char::deserialize(deserializer)?;
+		Color::from_char(c).ok_or_else(|| de::Error::custom(format!("unknown color: `{c}`")))
+	}
+}
+
+impl Serialize for Move {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_str(self.to_notation())
+	}
+}
+impl<'de> Deserialize<'de> for Move {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let notation = String::deserialize(deserializer)?;
+		Move::from_notation(&notation).ok_or_else(|| de::Error::custom(format!("unknown move: `{notation}`")))
+	}
+}
+
+impl Serialize for RubiksCube {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_str(&self.to_facelets())
+	}
+}
+impl<'de> Deserialize<'de> for RubiksCube {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		struct RubiksCubeVisitor;
+		impl<'de> Visitor<'de> for RubiksCubeVisitor {
+			type Value = RubiksCube;
+			fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				write!(f, "facelet string or array of 54 colors")
+			}
+			fn visit_str<E: de::Error>(self, facelets: &str) -> Result<RubiksCube, E> {
+				RubiksCube::from_facelets(facelets).map_err(E::custom)
+			}
+			fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<RubiksCube, A::Error> {
+				let mut pieces = [Color::W; 54];
+				for (i, piece) in pieces.iter_mut().enumerate() {
+					*piece = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(i, &self))?;
+				}
+				if seq.next_element::<Color>()?.is_some() {
+					return Err(de::Error::invalid_length(55, &self));
+				}
+				Ok(RubiksCube::from_pieces(pieces))
+			}
+		}
+		deserializer.deserialize_any(RubiksCubeVisitor)
+	}
+}
+
+macro_rules! impl_serde_via_rc {
+	($($t:ty),*) => { $(
+		impl Serialize for $t {
+			fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+				self.to_rc().serialize(serializer)
+			}
+		}
+		impl<'de> Deserialize<'de> for $t {
+			fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+				RubiksCube::deserialize(deserializer).map(<$t>::from_rc)
+			}
+		}
+	)* };
+}
+impl_serde_via_rc!(RubiksCubeCompressedX2, RubiksCubeCompressedX3, RubiksCubePacked);
+
+
+
+#[test]
+fn json() {
+	use rand::{rngs::StdRng, SeedableRng};
+	let rng = &mut StdRng::seed_from_u64(0);
+	let mut rc = RubiksCube::new();
+	let moves = rc.shuffle(20, rng);
+	let json = serde_json::to_string(&(&rc, &moves)).unwrap();
+	assert_eq!(format!("[\"{}\",[{}]]", rc.to_facelets(), moves.iter().map(|m| format!("\"{}\"", m.to_notation())).collect::<Vec<_>>().join(",")), json);
+	assert_eq!((rc.clone(), moves), serde_json::from_str(&json).unwrap());
+	let json_array = serde_json::to_string(rc.pieces.as_slice()).unwrap();
+	assert_eq!(rc, serde_json::from_str::<RubiksCube>(&json_array).unwrap());
+	let rc_x3 = rc.to_compressed_x3();
+	assert_eq!(rc_x3, serde_json::from_str(&serde_json::to_string(&rc_x3).unwrap()).unwrap());
+}
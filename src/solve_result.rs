@@ -0,0 +1,132 @@
+//! Result of one solve with its statistics, so output can be ingested by tools (as JSON with `serde` feature).
+//!
+//! Frontier statistics come from `log_ply`, that every solver already calls after each expansion,
+//! collected into a per-thread stack of solves.
+
+use std::{cell::RefCell, time::Instant};
+
+use crate::{Move, RubiksCube, Solver};
+
+
+
+thread_local! {
+	/// (begin, plies) of solves running on this thread, innermost last.
+	/// A rayon worker waiting inside one solve can run another one (stolen from the pool),
+	/// but finishes it before returning, so the last frame always belongs to the currently running solve.
+	static SOLVES: RefCell<Vec<(Instant, Vec<Ply>)>> = const { RefCell::new(vec![]) };
+}
+
+/// Frontier sizes after one expansion (of either side, including recursive sub-solves).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ply {
+	pub left_moves: u32,
+	pub right_moves: u32,
+	pub left_len: usize,
+	pub right_len: usize,
+	/// since the beginning of the solve.
+	pub elapsed_s: f64,
+}
+
+pub(crate) fn record_ply(left_moves: u32, right_moves: u32, left_len: usize, right_len: usize) {
+	SOLVES.with_borrow_mut(|solves| {
+		if let Some((time_begin, plies)) = solves.last_mut() {
+			let elapsed_s = time_begin.elapsed().as_secs_f64();
+			plies.push(Ply { left_moves, right_moves, left_len, right_len, elapsed_s });
+		}
+	});
+}
+
+/// Length of solution in different metrics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lengths {
+	/// number of `Move`s, as solvers count them (`X`, `Y` and `Z` are one move).
+	pub moves: usize,
+	/// half turn metric: any turn of one face is one move.
+	pub htm: usize,
+	/// quarter turn metric: half turn of a face is two moves.
+	pub qtm: usize,
+}
+impl Lengths {
+	pub fn of(moves: &[Move]) -> Self {
+		// (face, quarter turns clockwise) of consecutive turns of the same face, merged
+		let mut turns: Vec<(char, u8)> = vec![];
+		for move_ in moves {
+			for (face, quarters) in Self::face_turns(move_) {
+				match turns.last_mut() {
+					Some((face_last, quarters_last)) if *face_last == face => {
+						*quarters_last = (*quarters_last + quarters) % 4;
+						if *quarters_last == 0 {
+							turns.pop();
+						}
+					}
+					_ => turns.push((face, quarters)),
+				}
+			}
+		}
+		Self {
+			moves: moves.len(),
+			htm: turns.len(),
+			qtm: turns.iter().map(|&(_, quarters)| if quarters == 2 { 2 } else { 1 }).sum(),
+		}
+	}
+
+	fn face_turns(move_: &Move) -> Vec<(char, u8)> {
+		use Move::*;
+		match move_ {
+			Front => vec![('F', 1)], FrontS => vec![('F', 3)],
+			Back => vec![('B', 1)], BackS => vec![('B', 3)],
+			Left => vec![('L', 1)], LeftS => vec![('L', 3)],
+			Right => vec![('R', 1)], RightS => vec![('R', 3)],
+			Top => vec![('U', 1)], TopS => vec![('U', 3)],
+			Bottom => vec![('D', 1)], BottomS => vec![('D', 3)],
+			X => vec![('L', 3), ('R', 1)], Xs => vec![('L', 1), ('R', 3)],
+			Y => vec![('U', 1), ('D', 3)], Ys => vec![('U', 3), ('D', 1)],
+			Z => vec![('F', 1), ('B', 3)], Zs => vec![('F', 3), ('B', 1)],
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SolveResult {
+	pub solver: String,
+	pub scramble: RubiksCube,
+	pub solution: Vec<Move>,
+	pub lengths: Lengths,
+	pub time_s: f64,
+	pub plies: Vec<Ply>,
+	/// largest frontier at any ply, on either side.
+	pub frontier_len_max: usize,
+}
+impl SolveResult {
+	/// Solves `rc` to `RubiksCube::new` by `solver`, collecting statistics.
+	pub fn solve(solver_name: &str, solver: Solver, rc: &RubiksCube) -> Self {
+		SOLVES.with_borrow_mut(|solves| solves.push((Instant::now(), vec![])));
+		let solution = if *rc == RubiksCube::new() { vec![] } else { solver(rc) };
+		let (time_begin, plies) = SOLVES.with_borrow_mut(|solves| solves.pop().unwrap());
+		Self {
+			solver: solver_name.to_string(),
+			scramble: rc.clone(),
+			lengths: Lengths::of(&solution),
+			solution,
+			time_s: time_begin.elapsed().as_secs_f64(),
+			frontier_len_max: plies.iter().map(|ply| ply.left_len.max(ply.right_len)).max().unwrap_or(0),
+			plies,
+		}
+	}
+}
+
+
+
+#[test]
+fn lengths() {
+	use Move::*;
+	assert_eq!(Lengths { moves: 0, htm: 0, qtm: 0 }, Lengths::of(&[]));
+	assert_eq!(Lengths { moves: 2, htm: 1, qtm: 2 }, Lengths::of(&[Front, Front]));
+	assert_eq!(Lengths { moves: 3, htm: 1, qtm: 1 }, Lengths::of(&[Front, Front, Front]));
+	assert_eq!(Lengths { moves: 4, htm: 0, qtm: 0 }, Lengths::of(&[Top, Right, RightS, TopS]));
+	assert_eq!(Lengths { moves: 1, htm: 2, qtm: 2 }, Lengths::of(&[X]));
+	assert_eq!(Lengths { moves: 2, htm: 2, qtm: 3 }, Lengths::of(&[X, Right]));
+}
@@ -0,0 +1,127 @@
+//! Layers of states at exactly `k` moves from the solved cube, computed once and shared by all solves to it,
+//! so a solve only has to expand its own (left) side.
+//!
+//! Layers live in memory for the whole run, and if `SOLVED_SIDE_DIR` is set, also in `FrontierStore` between runs.
+
+use std::{
+	io,
+	path::PathBuf,
+	sync::{OnceLock, RwLock, RwLockReadGuard},
+};
+
+use crate::{
+	frontier::Frontier,
+	frontier_store::{FrontierStore, Layer},
+	Move, RubiksCube, RubiksCubeCompressedX3, SortedVec, ALL_MOVES,
+};
+
+
+
+#[doc(hidden)]
+pub static SOLVED_SIDE_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+static SOLVED_SIDE: RwLock<SolvedSide> = RwLock::new(SolvedSide { layers: Vec::new() });
+
+#[derive(Debug)]
+pub struct SolvedSide {
+	/// `layers[k]` are states reachable from the solved cube by exactly `k` moves.
+	layers: Vec<Layer>,
+}
+impl SolvedSide {
+	pub fn depth(&self) -> u32 {
+		self.layers.len() as u32 - 1
+	}
+
+	pub fn layer(&self, k: u32) -> &[RubiksCubeCompressedX3] {
+		self.layers[k as usize].as_ref()
+	}
+
+	/// Returns distance from `rc` to the solved cube, if it's at most `depth`.
+	pub fn distance(&self, rc: &RubiksCubeCompressedX3) -> Option<u32> {
+		self.layers.iter().position(|layer| layer.as_ref().binary_search(rc).is_ok()).map(|k| k as u32)
+	}
+
+	/// Returns moves from `rc`, that is in layer `k`, to the solved cube.
+	pub fn path_to_solved(&self, rc: &RubiksCube, k: u32) -> Vec<Move> {
+		moves_along(&walk_down(&self.layers[..=k as usize], rc.clone()))
+	}
+
+	fn extend_to(&mut self, depth: u32) {
+		if self.layers.is_empty() {
+			self.layers.push(Layer::Owned(SortedVec::from_item(RubiksCube::new().to_compressed_x3())));
+		}
+		let store = SOLVED_SIDE_DIR.get().map(|dir| FrontierStore::new(dir.clone(), "solved-side"));
+		while self.depth() < depth {
+			let k = self.depth() + 1;
+			let layer = match store.as_ref().map(|store| store.load(k)) {
+				Some(Ok(layer)) => layer,
+				loaded => {
+					if let Some(Err(e)) = loaded && e.kind() != io::ErrorKind::NotFound {
+						eprintln!("WARNING: can't load solved side layer {k}: {e}");
+					}
+					eprintln!("computing solved side layer {k}");
+					let layer_prev = SortedVec { items: self.layers.last().unwrap().as_ref().to_vec() };
+					let layer = layer_prev.expand(RubiksCubeCompressedX3::juxt);
+					if let Some(store) = store.as_ref() && let Err(e) = store.save(k, &layer.items) {
+						eprintln!("WARNING: can't save solved side layer {k}: {e}");
+					}
+					Layer::Owned(layer)
+				}
+			};
+			self.layers.push(layer);
+		}
+	}
+}
+
+/// Returns solved side with layers up to at least `depth`, computing (or loading) missing ones.
+/// Concurrent solves share it for reading.
+pub(crate) fn get(depth: u32) -> RwLockReadGuard<'static, SolvedSide> {
+	{
+		let solved_side = SOLVED_SIDE.read().unwrap();
+		if !solved_side.layers.is_empty() && solved_side.depth() >= depth {
+			return solved_side
+		}
+	}
+	SOLVED_SIDE.write().unwrap().extend_to(depth);
+	SOLVED_SIDE.read().unwrap()
+}
+
+
+
+/// Returns cubes from `rc`, that is in the last of `layers`, to one in the first layer,
+/// each one move from the previous and in the previous layer.
+pub(crate) fn walk_down(layers: &[impl AsRef<[RubiksCubeCompressedX3]>], rc: RubiksCube) -> Vec<RubiksCube> {
+	let mut path: Vec<RubiksCube> = vec![rc];
+	for layer in layers[..layers.len()-1].iter().rev() {
+		let rc_prev = path.last().unwrap().juxt().into_iter()
+			.find(|rc_prev| layer.as_ref().binary_search(&rc_prev.to_compressed_x3()).is_ok())
+			.expect("every state of a layer has a neighbour in the previous layer");
+		path.push(rc_prev);
+	}
+	path
+}
+
+/// Returns moves, that lead through `path` of cubes, each one move from the previous.
+pub(crate) fn moves_along(path: &[RubiksCube]) -> Vec<Move> {
+	path.windows(2)
+		.map(|rcs| ALL_MOVES[rcs[0].juxt().into_iter().position(|rc| rc == rcs[1]).unwrap()].clone())
+		.collect()
+}
+
+
+
+#[test]
+fn layers() {
+	let solved_side = get(3);
+	let rc_new = RubiksCube::new();
+	assert_eq!(Some(0), solved_side.distance(&rc_new.to_compressed_x3()));
+	assert_eq!(18, solved_side.layer(1).len());
+	let mut rc = rc_new.clone();
+	rc.make_moves(&[Move::Front, Move::Top, Move::Right]);
+	let k = solved_side.distance(&rc.to_compressed_x3()).unwrap();
+	assert_eq!(3, k);
+	let solution = solved_side.path_to_solved(&rc, k);
+	assert_eq!(3, solution.len());
+	rc.make_moves(&solution);
+	assert_eq!(rc_new, rc);
+}
@@ -0,0 +1,231 @@
+//! Supercube: cube with orientation of each center tracked, as needed by picture cubes,
+//! where a center turned by a quarter isn't solved although all colors are.
+//!
+//! Orientation of a center is the number of clockwise quarter turns of its face (mod 4),
+//! so `X`, `Y` and `Z` turn two centers, one each way. Turns of each move come from its sticker permutation:
+//! stickers around a center are rotated by as many quarters as the center is.
+//!
+//! Reachable states are those whose colors are, with parity of all center turns equal to parity of corner permutation
+//! (every quarter turn changes both), see `Supercube::is_solvable`.
+
+use std::sync::LazyLock;
+
+use crate::{
+	cubie::{is_odd, CubieCube},
+	frontier::Frontier,
+	log_ply, verify, Alg, Move, RubiksCube, RubiksCubeCompressedX3, SortedVec, ALL_MOVES,
+};
+
+
+
+/// Stickers next to centers U, L, F, R, B, D, clockwise as seen from outside the face.
+const CENTER_RINGS: [[usize; 4]; 6] = [
+	[1, 5, 7, 3],
+	[10, 23, 34, 21],
+	[13, 26, 37, 24],
+	[16, 29, 40, 27],
+	[19, 32, 43, 30],
+	[46, 50, 52, 48],
+];
+
+/// `CENTER_TURNS[move_ as usize][c]` is number of clockwise quarter turns `move_` gives to center `c`.
+static CENTER_TURNS: LazyLock<[[u8; 6]; 18]> = LazyLock::new(|| {
+	ALL_MOVES.map(|move_| {
+		let permutation = move_.sticker_permutation();
+		CENTER_RINGS.map(|ring| {
+			(0..4u8)
+				.find(|&turns| (0..4).all(|j| permutation[ring[(j + turns as usize) % 4]] == ring[j]))
+				.unwrap_or(0)
+		})
+	})
+});
+
+
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Supercube {
+	rc: RubiksCube,
+	/// clockwise quarter turns of centers U, L, F, R, B, D (mod 4).
+	centers: [u8; 6],
+}
+impl Supercube {
+	pub fn new() -> Self {
+		Self { rc: RubiksCube::new(), centers: [0; 6] }
+	}
+
+	pub fn from_parts(rc: RubiksCube, centers: [u8; 6]) -> Self {
+		Self { rc, centers: centers.map(|turns| turns % 4) }
+	}
+
+	pub fn rc(&self) -> &RubiksCube {
+		&self.rc
+	}
+
+	pub fn centers(&self) -> [u8; 6] {
+		self.centers
+	}
+
+	pub fn make_move(&mut self, move_: Move) {
+		for (center, turns) in self.centers.iter_mut().zip(CENTER_TURNS[move_.clone() as usize]) {
+			*center = (*center + turns) % 4;
+		}
+		self.rc.make_move(move_);
+	}
+
+	pub fn make_moves(&mut self, moves: &[Move]) {
+		for move_ in moves {
+			self.make_move(move_.clone());
+		}
+	}
+
+	pub fn apply(&mut self, alg: &Alg) {
+		self.make_moves(alg.moves());
+	}
+
+	/// Whether moves can bring this state to `Supercube::new`.
+	pub fn is_solvable(&self) -> bool {
+		let Ok(cc) = CubieCube::from_rc(&self.rc) else { return false };
+		let turns: u32 = self.centers.iter().map(|&turns| turns as u32).sum();
+		cc.verify().is_ok() && (turns % 2 == 1) == is_odd(&cc.cp)
+	}
+
+	fn to_compressed(&self) -> SupercubeCompressed {
+		let centers = self.centers.iter().rev().fold(0, |acc, &turns| (acc << 2) | turns as u16);
+		SupercubeCompressed { rc: self.rc.to_compressed_x3(), centers }
+	}
+
+	/// Solves to `goal` by meet-in-the-middle over colors and centers (recursing into halves, as other solvers),
+	/// shortest solution.
+	pub fn solve<F: Frontier<SupercubeCompressed>>(&self, goal: &Supercube) -> Vec<Move> {
+		assert!(
+			self.is_solvable() && goal.is_solvable(),
+			"states must be solvable: unsolvable ones can be in different orbits, that no moves connect",
+		);
+		if self == goal { return vec![] }
+		let mut left: F = F::from_item(self.to_compressed());
+		let mut right: F = F::from_item(goal.to_compressed());
+		let mut left_moves: u32 = 0;
+		let mut right_moves: u32 = 0;
+
+		let middle = loop {
+			log_ply(left_moves, right_moves, left.len(), right.len());
+
+			left = left.expand(SupercubeCompressed::juxt);
+			left_moves += 1;
+
+			log_ply(left_moves, right_moves, left.len(), right.len());
+
+			if let Some(middle) = left.intersection_with(&right) {
+				break middle
+			}
+
+			right = right.expand(SupercubeCompressed::juxt);
+			right_moves += 1;
+
+			if let Some(middle) = left.intersection_with(&right) {
+				break middle
+			}
+		};
+
+		let solution = if left_moves + right_moves == 1 {
+			let m = self.juxt().into_iter().position(|sc| sc == *goal).unwrap();
+			vec![ALL_MOVES[m].clone()]
+		}
+		else {
+			let middle = middle.to_supercube();
+			[self.solve::<F>(&middle), middle.solve::<F>(goal)].concat()
+		};
+		verify::debug_check(&self.rc, &solution, &goal.rc);
+		debug_assert!({
+			let mut sc = self.clone();
+			sc.make_moves(&solution);
+			sc.centers == goal.centers
+		}, "supercube solver returned wrong center orientation");
+		solution
+	}
+
+	pub fn juxt(&self) -> [Self; 18] {
+		ALL_MOVES.map(|move_| {
+			let mut sc = self.clone();
+			sc.make_move(move_);
+			sc
+		})
+	}
+}
+impl Default for Supercube {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// `Supercube` as frontier item: compressed colors, and 2 bits per center.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SupercubeCompressed {
+	rc: RubiksCubeCompressedX3,
+	centers: u16,
+}
+impl SupercubeCompressed {
+	fn to_supercube(self) -> Supercube {
+		Supercube { rc: self.rc.to_rc(), centers: std::array::from_fn(|c| (self.centers >> (2 * c)) as u8 & 3) }
+	}
+
+	fn juxt(&self) -> [Self; 18] {
+		self.to_supercube().juxt().map(|sc| sc.to_compressed())
+	}
+}
+
+
+
+/// `solve --supercube` command: solves scramble (notation only, as facelets don't show center orientation)
+/// to solved colors and centers, printing progress, then `solution: ` and the solution.
+#[doc(hidden)]
+pub fn run(scramble: &str) {
+	let moves = Move::moves_from_notation(scramble).unwrap_or_else(|e| panic!("supercube scramble must be moves: {e}"));
+	let mut sc = Supercube::new();
+	sc.make_moves(&moves);
+	let solution = sc.solve::<SortedVec<SupercubeCompressed>>(&Supercube::new());
+	println!("solution: {}", Move::moves_to_notation(&solution));
+}
+
+
+
+#[test]
+fn supercube() {
+	use crate::HashSetFrontier;
+	let turns = |move_: Move| CENTER_TURNS[move_ as usize];
+	assert_eq!([0, 0, 1, 0, 0, 0], turns(Move::Front));
+	assert_eq!([0, 0, 0, 0, 0, 3], turns(Move::BottomS));
+	assert_eq!([0, 3, 0, 1, 0, 0], turns(Move::X));
+	assert_eq!([1, 0, 0, 0, 0, 3], turns(Move::Y));
+	assert_eq!([0, 0, 1, 0, 3, 0], turns(Move::Z));
+
+	let mut sc = Supercube::new();
+	sc.apply(&Alg::from_notation("[R, U]").unwrap().repeat(6));
+	assert_eq!(Supercube::new(), sc);
+	sc.apply(&Alg::from_notation("U R L U2 R' L' U R L U2 R' L'").unwrap());
+	// colors are solved, but U center is turned by a half
+	assert_eq!(&RubiksCube::new(), sc.rc());
+	assert_eq!([2, 0, 0, 0, 0, 0], sc.centers());
+	assert!(sc.is_solvable());
+	assert!(!Supercube::from_parts(RubiksCube::new(), [1, 0, 0, 0, 0, 0]).is_solvable());
+
+	crate::VERBOSE.store(false, std::sync::atomic::Ordering::Relaxed);
+	use rand::{rngs::StdRng, SeedableRng};
+	let rng = &mut StdRng::seed_from_u64(0);
+	for _ in 0..5 {
+		let mut start = Supercube::new();
+		start.make_moves(&RubiksCube::new().shuffle(4, rng));
+		let mut goal = Supercube::new();
+		goal.make_moves(&RubiksCube::new().shuffle(2, rng));
+		let solution = start.solve::<SortedVec<SupercubeCompressed>>(&goal);
+		assert!(solution.len() <= 6);
+		assert_eq!(solution.len(), start.solve::<HashSetFrontier<SupercubeCompressed>>(&goal).len());
+		let mut sc = start.clone();
+		sc.make_moves(&solution);
+		assert_eq!(goal, sc);
+		// solving colors only is never longer
+		if start.rc() != goal.rc() {
+			assert!(start.rc().solve_compressed_x3_sorted_vec(goal.rc()).len() <= solution.len());
+		}
+	}
+}
@@ -0,0 +1,206 @@
+//! 48 spatial symmetries of the cube (24 rotations, each optionally mirrored left-right) acting on stickers,
+//! and reduction of a state to the minimal representative of its symmetry class.
+//!
+//! Whole-cube rotations aren't moves here (`x`, `y` and `z` turn two opposite faces, centers stay),
+//! so they are built from `Move::Y` / `Move::Z` plus the middle slice between turned faces.
+//! Applying symmetry also relabels colors by where the centers went, so the solved cube maps to itself.
+//!
+//! Move set is closed under conjugation by any symmetry, so neighbours of equivalent states are equivalent,
+//! and a search can expand representatives only.
+
+use std::sync::LazyLock;
+
+use crate::{Color, Move, RubiksCube, ALL_MOVES};
+
+
+
+/// `permutation[i]` is sticker moved to index `i`.
+type Permutation = [usize; 54];
+
+const CENTERS: [usize; 6] = [4, 22, 25, 28, 31, 49];
+
+/// Stickers turned by `Move::Front` outside of face F, and ones of the next layer (S slice) behind them.
+const FRONT_RING: [usize; 12] = [6, 7, 8, 15, 27, 39, 45, 46, 47, 11, 23, 35];
+const FRONT_RING_NEXT: [usize; 12] = [3, 4, 5, 16, 28, 40, 48, 49, 50, 10, 22, 34];
+
+/// `SYMMETRIES[0]` is identity.
+pub static SYMMETRIES: LazyLock<Vec<Permutation>> = LazyLock::new(|| {
+	let identity: Permutation = std::array::from_fn(|i| i);
+	let generators = [rotation_y(), rotation_z(), mirror()];
+	let mut symmetries: Vec<Permutation> = vec![identity];
+	let mut i = 0;
+	while i < symmetries.len() {
+		for generator in generators.iter() {
+			let symmetry = compose(&symmetries[i], generator);
+			if !symmetries.contains(&symmetry) {
+				symmetries.push(symmetry);
+			}
+		}
+		i += 1;
+	}
+	assert_eq!(48, symmetries.len());
+	symmetries
+});
+
+/// `MOVES_SYMMETRIC[s][m]` is `ALL_MOVES[m].symmetric(s)`.
+static MOVES_SYMMETRIC: LazyLock<Vec<[Move; 18]>> = LazyLock::new(|| {
+	let permutations = ALL_MOVES.map(|move_| move_.sticker_permutation());
+	SYMMETRIES.iter()
+		.map(|symmetry| {
+			let mut inverse: Permutation = [0; 54];
+			for (i, &j) in symmetry.iter().enumerate() {
+				inverse[j] = i;
+			}
+			permutations.map(|permutation| {
+				let conjugate: Permutation = std::array::from_fn(|i| inverse[permutation[symmetry[i]]]);
+				ALL_MOVES[permutations.iter().position(|p| *p == conjugate).expect("move set must be closed under symmetries")].clone()
+			})
+		})
+		.collect()
+});
+
+/// Index into `SYMMETRIES` of the one putting centers `CENTERS` (U, L, F, R, B, D) to `centers`,
+/// e.g. `[4, 28, 25, 22, 31, 49]` for mirror swapping L and R.
+pub fn symmetry_by_centers(centers: [usize; 6]) -> usize {
+	SYMMETRIES.iter()
+		.position(|symmetry| CENTERS.iter().zip(centers).all(|(&center, center_new)| symmetry[center_new] == center))
+		.expect("centers must be a symmetry of the cube")
+}
+
+/// Permutation of `g` then `h`.
+fn compose(g: &Permutation, h: &Permutation) -> Permutation {
+	std::array::from_fn(|i| g[h[i]])
+}
+
+/// Whole cube rotation as `Move::Y` (U clockwise, D counterclockwise) with E slice along.
+fn rotation_y() -> Permutation {
+	let mut permutation = Move::Y.sticker_permutation();
+	// top row of the side band is turned by U, middle row goes along
+	for i in 9..21 {
+		permutation[i + 12] = permutation[i] + 12;
+	}
+	permutation
+}
+
+/// Whole cube rotation as `Move::Z` (F clockwise, B counterclockwise) with S slice along.
+fn rotation_z() -> Permutation {
+	let mut permutation = Move::Z.sticker_permutation();
+	for (&i, &i_next) in FRONT_RING.iter().zip(&FRONT_RING_NEXT) {
+		let j = FRONT_RING.iter().position(|&j| j == permutation[i]).unwrap();
+		permutation[i_next] = FRONT_RING_NEXT[j];
+	}
+	permutation
+}
+
+/// Reflection swapping faces L and R.
+fn mirror() -> Permutation {
+	std::array::from_fn(|i| match i {
+		0..9 | 45..54 => i - i % 3 + (2 - i % 3),
+		_ => {
+			let (row_start, column) = (i - (i - 9) % 12, (i - 9) % 12);
+			row_start + (20 - column) % 12
+		}
+	})
+}
+
+
+
+
+impl RubiksCube {
+	/// Returns cube seen through `SYMMETRIES[s]`, with colors relabeled so that centers are the same as of `self`.
+	pub fn symmetric(&self, s: usize) -> RubiksCube {
+		let symmetry = &SYMMETRIES[s];
+		let relabel = self.relabel(symmetry);
+		RubiksCube { pieces: std::array::from_fn(|i| relabel[self.pieces[symmetry[i]] as usize]) }
+	}
+
+	/// `relabel[color]` is color, that a sticker of `color` gets after `symmetry`.
+	fn relabel(&self, symmetry: &Permutation) -> [Color; 6] {
+		let mut relabel = [Color::W; 6];
+		for c in CENTERS {
+			relabel[self.pieces[symmetry[c]] as usize] = self.pieces[c];
+		}
+		relabel
+	}
+
+	/// Returns minimal (by `Ord`) cube among all 48 symmetric ones.
+	pub fn canonicalize_symmetry(&self) -> RubiksCube {
+		let mut best = self.clone();
+		for symmetry in SYMMETRIES[1..].iter() {
+			let relabel = self.relabel(symmetry);
+			// compares lazily, as most symmetries lose on first stickers
+			for i in 0..54 {
+				let color = relabel[self.pieces[symmetry[i]] as usize];
+				match color.cmp(&best.pieces[i]) {
+					std::cmp::Ordering::Equal => {}
+					std::cmp::Ordering::Greater => break,
+					std::cmp::Ordering::Less => {
+						best.pieces[i] = color;
+						for j in i+1..54 {
+							best.pieces[j] = relabel[self.pieces[symmetry[j]] as usize];
+						}
+						break
+					}
+				}
+			}
+		}
+		best
+	}
+}
+impl Move {
+	/// Move that does to `rc.symmetric(s)` what this move does to `rc`.
+	pub fn symmetric(&self, s: usize) -> Move {
+		MOVES_SYMMETRIC[s][self.clone() as usize].clone()
+	}
+}
+
+
+
+/// Checks, that every symmetry maps solved cube to itself and every move to a move.
+#[test]
+fn symmetries() {
+	use crate::ALL_MOVES;
+	use rand::{rngs::StdRng, SeedableRng};
+	let rng = &mut StdRng::seed_from_u64(0);
+	let rc_new = RubiksCube::new();
+	for s in 0..48 {
+		assert_eq!(rc_new, rc_new.symmetric(s));
+	}
+	let rcs: Vec<RubiksCube> = (0..20).map(|_| RubiksCube::new_shuffled(30, rng)).collect();
+	for s in 0..48 {
+		for move_ in ALL_MOVES {
+			let rc = &rcs[0];
+			let mut rc_moved = rc.clone();
+			rc_moved.make_move(move_.clone());
+			let rc_moved_symmetric = rc_moved.symmetric(s);
+			let move_conjugate = ALL_MOVES.iter().find(|move_conjugate| {
+				let mut rc_symmetric_moved = rc.symmetric(s);
+				rc_symmetric_moved.make_move((*move_conjugate).clone());
+				rc_symmetric_moved == rc_moved_symmetric
+			}).expect("move set must be closed under symmetries");
+			for rc in rcs[1..].iter() {
+				let mut rc_moved = rc.clone();
+				rc_moved.make_move(move_.clone());
+				let mut rc_symmetric_moved = rc.symmetric(s);
+				rc_symmetric_moved.make_move(move_conjugate.clone());
+				assert_eq!(rc_symmetric_moved, rc_moved.symmetric(s));
+			}
+			assert_eq!(move_conjugate, &move_.symmetric(s));
+		}
+	}
+}
+
+#[test]
+fn canonicalize_symmetry() {
+	use rand::{rngs::StdRng, SeedableRng};
+	let rng = &mut StdRng::seed_from_u64(0);
+	assert_eq!(RubiksCube::new(), RubiksCube::new().canonicalize_symmetry());
+	for _ in 0..20 {
+		let rc = RubiksCube::new_shuffled(30, rng);
+		let rc_canonical = (0..48).map(|s| rc.symmetric(s)).min().unwrap();
+		assert_eq!(rc_canonical, rc.canonicalize_symmetry());
+		for s in 0..48 {
+			assert_eq!(rc_canonical, rc.symmetric(s).canonicalize_symmetry());
+		}
+	}
+}
@@ -0,0 +1,219 @@
+//! Two-phase solver (Kociemba's algorithm): solves any state in well under a second, but not optimally,
+//! so it's used to turn random states into scrambles rather than as one of the meet-in-the-middle solvers.
+//!
+//! Phase 1 brings the cube into subgroup G1 = <U, D, Y, F2, B2, L2, R2, X2, Z2> (no twist, no flip,
+//! UD-slice edges in the slice) with all 18 moves, phase 2 solves it within G1.
+//! Both are IDA* searches over coordinates, with pruning tables of exact distances of pairs of coordinates.
+//! Half turns of G1 are pairs of the same quarter turn, as the move set has no half turns.
+
+use std::sync::LazyLock;
+
+use crate::{
+	coord::{CoordCube, FLIP_MOVE, FLIPS_N, SLICE_MOVE, SLICES_N, TWIST_MOVE, TWISTS_N},
+	cubie::{rank_permutation, unrank_permutation, CubieCube, MOVE_CUBIES},
+	verify, Move, RubiksCube, ALL_MOVES,
+};
+
+
+
+/// permutations of corners, of UD edges (UR..DB, within positions 0..8) and of slice edges (FR..BR, within 8..12).
+const CORNER_PERMS_N: usize = 40320;
+const UD_EDGE_PERMS_N: usize = 40320;
+const SLICE_PERMS_N: usize = 24;
+
+/// Moves of G1, as indices into `ALL_MOVES`.
+const PHASE2_MOVES: [&[usize]; 12] = [&[8], &[9], &[10], &[11], &[14], &[15], &[0, 0], &[2, 2], &[4, 4], &[6, 6], &[12, 12], &[16, 16]];
+/// Inverses of moves of G1, as indices into `PHASE2_MOVES` (half turns are their own).
+const PHASE2_INVERSES: [usize; 12] = [1, 0, 3, 2, 5, 4, 6, 7, 8, 9, 10, 11];
+
+const UNVISITED: u8 = u8::MAX;
+
+struct Tables {
+	/// `[twist * SLICES_N + slice]`
+	twist_slice_prune: Vec<u8>,
+	/// `[flip * SLICES_N + slice]`
+	flip_slice_prune: Vec<u8>,
+	/// `[coordinate][phase 2 move]`
+	corner_perm_move: Vec<[u16; 12]>,
+	ud_edge_perm_move: Vec<[u16; 12]>,
+	slice_perm_move: Vec<[u8; 12]>,
+	/// `[corner_perm * SLICE_PERMS_N + slice_perm]`
+	corner_slice_prune: Vec<u8>,
+	/// `[ud_edge_perm * SLICE_PERMS_N + slice_perm]`
+	ud_edge_slice_prune: Vec<u8>,
+}
+
+static SLICE_SOLVED: LazyLock<u16> = LazyLock::new(|| CoordCube::from_cubie(&CubieCube::SOLVED).slice());
+
+static TABLES: LazyLock<Tables> = LazyLock::new(|| {
+	let twist_slice_prune = prune_table(TWISTS_N, SLICES_N, (0, *SLICE_SOLVED as usize), 18, |a, b, m| {
+		(TWIST_MOVE[a][m] as usize, SLICE_MOVE[b][m] as usize)
+	});
+	let flip_slice_prune = prune_table(FLIPS_N, SLICES_N, (0, *SLICE_SOLVED as usize), 18, |a, b, m| {
+		(FLIP_MOVE[a][m] as usize, SLICE_MOVE[b][m] as usize)
+	});
+
+	let phase2_cubies: [CubieCube; 12] = PHASE2_MOVES.map(|moves| {
+		moves.iter().fold(CubieCube::SOLVED, |cc, &m| cc.multiply(&MOVE_CUBIES[m]))
+	});
+	let corner_perm_move: Vec<[u16; 12]> = (0..CORNER_PERMS_N).map(|corner_perm| {
+		let cc = CubieCube { cp: unrank_permutation(corner_perm as u64), ..CubieCube::SOLVED };
+		phase2_cubies.each_ref().map(|move_cubie| rank_permutation(&cc.multiply(move_cubie).cp) as u16)
+	}).collect();
+	let ud_edge_perm_move: Vec<[u16; 12]> = (0..UD_EDGE_PERMS_N).map(|ud_edge_perm| {
+		let cc = CubieCube { ep: ud_edge_perm_to_ep(ud_edge_perm as u16), ..CubieCube::SOLVED };
+		phase2_cubies.each_ref().map(|move_cubie| ep_to_ud_edge_perm(&cc.multiply(move_cubie).ep))
+	}).collect();
+	let slice_perm_move: Vec<[u8; 12]> = (0..SLICE_PERMS_N).map(|slice_perm| {
+		let cc = CubieCube { ep: slice_perm_to_ep(slice_perm as u8), ..CubieCube::SOLVED };
+		phase2_cubies.each_ref().map(|move_cubie| ep_to_slice_perm(&cc.multiply(move_cubie).ep))
+	}).collect();
+	let corner_slice_prune = prune_table(CORNER_PERMS_N, SLICE_PERMS_N, (0, 0), 12, |a, b, m| {
+		(corner_perm_move[a][m] as usize, slice_perm_move[b][m] as usize)
+	});
+	let ud_edge_slice_prune = prune_table(UD_EDGE_PERMS_N, SLICE_PERMS_N, (0, 0), 12, |a, b, m| {
+		(ud_edge_perm_move[a][m] as usize, slice_perm_move[b][m] as usize)
+	});
+
+	Tables {
+		twist_slice_prune, flip_slice_prune,
+		corner_perm_move, ud_edge_perm_move, slice_perm_move,
+		corner_slice_prune, ud_edge_slice_prune,
+	}
+});
+
+/// Distances from `solved` of all pairs `a * b_n + b` (breadth first search).
+fn prune_table(a_n: usize, b_n: usize, solved: (usize, usize), moves_n: usize, moved: impl Fn(usize, usize, usize) -> (usize, usize)) -> Vec<u8> {
+	let mut table = vec![UNVISITED; a_n * b_n];
+	table[solved.0 * b_n + solved.1] = 0;
+	let mut layer = vec![solved];
+	let mut depth = 0;
+	while !layer.is_empty() {
+		depth += 1;
+		let mut layer_next = vec![];
+		for (a, b) in layer {
+			for m in 0..moves_n {
+				let (a_new, b_new) = moved(a, b, m);
+				let distance = &mut table[a_new * b_n + b_new];
+				if *distance == UNVISITED {
+					*distance = depth;
+					layer_next.push((a_new, b_new));
+				}
+			}
+		}
+		layer = layer_next;
+	}
+	table
+}
+
+fn ep_to_ud_edge_perm(ep: &[u8; 12]) -> u16 {
+	rank_permutation(&ep[..8]) as u16
+}
+fn ud_edge_perm_to_ep(ud_edge_perm: u16) -> [u8; 12] {
+	let mut ep = CubieCube::SOLVED.ep;
+	ep[..8].copy_from_slice(&unrank_permutation::<8>(ud_edge_perm as u64));
+	ep
+}
+
+fn ep_to_slice_perm(ep: &[u8; 12]) -> u8 {
+	rank_permutation(&ep[8..].iter().map(|&e| e - 8).collect::<Vec<u8>>()) as u8
+}
+fn slice_perm_to_ep(slice_perm: u8) -> [u8; 12] {
+	let mut ep = CubieCube::SOLVED.ep;
+	ep[8..].copy_from_slice(&unrank_permutation::<4>(slice_perm as u64).map(|e| e + 8));
+	ep
+}
+
+
+
+/// Returns moves that bring `cc` to `CubieCube::SOLVED`.
+pub fn solve(cc: &CubieCube) -> Vec<Move> {
+	let tables = &*TABLES;
+	let coord = CoordCube::from_cubie(cc);
+	let mut moves = vec![];
+	for depth in 0.. {
+		if phase1(tables, cc, (coord.twist, coord.flip, coord.slice()), depth, &mut moves) {
+			let solution: Vec<Move> = moves.into_iter().map(|m| ALL_MOVES[m].clone()).collect();
+			verify::debug_check(&cc.to_rc(), &solution, &RubiksCube::new());
+			return solution
+		}
+	}
+	unreachable!()
+}
+
+/// Searches phase 1 solutions of exactly `depth` more moves, and for the first one found solves phase 2 (appending to `moves`).
+fn phase1(tables: &Tables, cc: &CubieCube, (twist, flip, slice): (u16, u16, u16), depth: u32, moves: &mut Vec<usize>) -> bool {
+	let distance = tables.twist_slice_prune[twist as usize * SLICES_N + slice as usize]
+		.max(tables.flip_slice_prune[flip as usize * SLICES_N + slice as usize]) as u32;
+	if distance > depth { return false }
+	if depth == 0 {
+		let cc = moves.iter().fold(*cc, |cc, &m| cc.multiply(&MOVE_CUBIES[m]));
+		let corner_perm = rank_permutation(&cc.cp) as u16;
+		let mut moves2 = vec![];
+		for depth2 in 0.. {
+			if phase2(tables, (corner_perm, ep_to_ud_edge_perm(&cc.ep), ep_to_slice_perm(&cc.ep)), depth2, &mut moves2) {
+				moves.extend(moves2.iter().flat_map(|&m2| PHASE2_MOVES[m2]));
+				return true
+			}
+		}
+	}
+	for m in 0..18 {
+		// inverse of the previous move, or the third same quarter turn in a row
+		match moves.as_slice() {
+			[.., prev] if *prev == m ^ 1 => continue,
+			[.., prev2, prev] if *prev2 == m && *prev == m => continue,
+			_ => {}
+		}
+		moves.push(m);
+		let coord_new = (TWIST_MOVE[twist as usize][m], FLIP_MOVE[flip as usize][m], SLICE_MOVE[slice as usize][m]);
+		if phase1(tables, cc, coord_new, depth - 1, moves) { return true }
+		moves.pop();
+	}
+	false
+}
+
+fn phase2(tables: &Tables, (corner_perm, ud_edge_perm, slice_perm): (u16, u16, u8), depth: u32, moves: &mut Vec<usize>) -> bool {
+	let distance = tables.corner_slice_prune[corner_perm as usize * SLICE_PERMS_N + slice_perm as usize]
+		.max(tables.ud_edge_slice_prune[ud_edge_perm as usize * SLICE_PERMS_N + slice_perm as usize]) as u32;
+	if distance > depth { return false }
+	if depth == 0 { return true }
+	for (m, &inverse) in PHASE2_INVERSES.iter().enumerate() {
+		// inverse of the previous move, or the third same quarter turn in a row
+		match moves.as_slice() {
+			[.., prev] if *prev == inverse => continue,
+			[.., prev2, prev] if *prev2 == m && *prev == m => continue,
+			_ => {}
+		}
+		moves.push(m);
+		let coord_new = (
+			tables.corner_perm_move[corner_perm as usize][m],
+			tables.ud_edge_perm_move[ud_edge_perm as usize][m],
+			tables.slice_perm_move[slice_perm as usize][m],
+		);
+		if phase2(tables, coord_new, depth - 1, moves) { return true }
+		moves.pop();
+	}
+	false
+}
+
+
+
+#[test]
+fn solves() {
+	use rand::{rngs::StdRng, SeedableRng};
+	let rng = &mut StdRng::seed_from_u64(0);
+	assert_eq!(Vec::<Move>::new(), solve(&CubieCube::SOLVED));
+	// half turn of U within G1 is solved by two quarter turns
+	let u2 = CubieCube::SOLVED.multiply(&MOVE_CUBIES[8]).multiply(&MOVE_CUBIES[8]);
+	assert_eq!(2, solve(&u2).len());
+	for _ in 0..20 {
+		let cc = CubieCube::random(rng);
+		let mut rc = cc.to_rc();
+		rc.make_moves(&solve(&cc));
+		assert_eq!(RubiksCube::new(), rc);
+	}
+	let (rc, scramble) = RubiksCube::new_random_state(rng);
+	let mut rc_scrambled = RubiksCube::new();
+	rc_scrambled.make_moves(&scramble);
+	assert_eq!(rc, rc_scrambled);
+}
@@ -0,0 +1,131 @@
+//! Checks that moves bring a cube to a goal (`verify` command), and that solvers' answers do (in debug builds).
+
+use std::fmt;
+
+use crate::{Color, Move, RubiksCube};
+
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Goal {
+	/// every sticker as in this cube.
+	State(RubiksCube),
+	/// only these stickers, as `(index, color)`, e.g. a solved face.
+	Stickers(Vec<(usize, Color)>),
+}
+impl Goal {
+	pub fn solved() -> Self {
+		Goal::State(RubiksCube::new())
+	}
+
+	/// Stickers of `rc` that don't match, in order of `Goal`.
+	fn mismatches(&self, rc: &RubiksCube) -> Vec<Mismatch> {
+		let expected: Vec<(usize, Color)> = match self {
+			Goal::State(goal) => goal.pieces().iter().copied().enumerate().collect(),
+			Goal::Stickers(stickers) => stickers.clone(),
+		};
+		expected.into_iter()
+			.filter(|&(index, color)| rc.pieces()[index] != color)
+			.map(|(index, expected)| Mismatch { index, expected, actual: rc.pieces()[index] })
+			.collect()
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mismatch {
+	pub index: usize,
+	pub expected: Color,
+	pub actual: Color,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifyError {
+	/// in order of `Goal` (of sticker index for `Goal::State`), never empty.
+	pub mismatches: Vec<Mismatch>,
+	/// cube after all moves.
+	pub end: RubiksCube,
+}
+impl fmt::Display for VerifyError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		const SHOWN_N: usize = 5;
+		let shown: Vec<String> = self.mismatches.iter().take(SHOWN_N)
+			.map(|mismatch| format!("{} is {} instead of {}", mismatch.index, mismatch.actual.to_char(), mismatch.expected.to_char()))
+			.collect();
+		write!(f, "{} stickers differ from goal: {}", self.mismatches.len(), shown.join(", "))?;
+		if self.mismatches.len() > SHOWN_N {
+			write!(f, ", ...")?;
+		}
+		Ok(())
+	}
+}
+impl std::error::Error for VerifyError {}
+
+
+
+/// Replays `moves` on `start`, and checks the result against `goal`.
+///
+/// ```
+/// # use rubiks_cube_solver_rs::{verify::{verify, Goal}, Move, RubiksCube};
+/// let mut rc = RubiksCube::new();
+/// rc.make_move(Move::Right);
+/// assert!(verify(&rc, &[Move::RightS], &Goal::solved()).is_ok());
+/// assert_eq!(12, verify(&rc, &[Move::Right], &Goal::solved()).unwrap_err().mismatches.len());
+/// ```
+pub fn verify(start: &RubiksCube, moves: &[Move], goal: &Goal) -> Result<(), VerifyError> {
+	let mut rc = start.clone();
+	for move_ in moves {
+		rc.make_move(move_.clone());
+	}
+	let mismatches = goal.mismatches(&rc);
+	if mismatches.is_empty() { Ok(()) } else { Err(VerifyError { mismatches, end: rc }) }
+}
+
+/// Panics if `moves` don't bring `start` to `goal`, in debug builds only. Called by solvers before returning.
+pub(crate) fn debug_check(start: &RubiksCube, moves: &[Move], goal: &RubiksCube) {
+	if cfg!(debug_assertions) && let Err(e) = verify(start, moves, &Goal::State(goal.clone())) {
+		panic!("solver returned wrong solution ({}): {e}", Move::moves_to_notation(moves));
+	}
+}
+
+/// `verify` command: checks that `notation` solves cube given by `scramble` (notation or facelets, as in `batch`),
+/// returns whether it does.
+#[doc(hidden)]
+pub fn run(scramble: &str, notation: &str) -> bool {
+	let rc = RubiksCube::parse_scramble(scramble).unwrap_or_else(|e| panic!("invalid scramble: {e}"));
+	let moves = Move::moves_from_notation(notation).unwrap_or_else(|e| panic!("invalid moves: {e}"));
+	match verify(&rc, &moves, &Goal::solved()) {
+		Ok(()) => {
+			println!("ok: {} moves solve the cube", moves.len());
+			true
+		}
+		Err(e) => {
+			println!("wrong: {e}");
+			false
+		}
+	}
+}
+
+
+
+#[test]
+fn verify_moves() {
+	let moves = Move::moves_from_notation("R U F' L2").unwrap();
+	let mut rc = RubiksCube::new();
+	rc.make_moves(&moves);
+	assert_eq!(Ok(()), verify(&rc, &Move::inverse_moves(&moves), &Goal::solved()));
+	assert_eq!(Ok(()), verify(&RubiksCube::new(), &moves, &Goal::State(rc.clone())));
+	assert_eq!(Ok(()), verify(&rc, &[], &Goal::State(rc.clone())));
+
+	let e = verify(&rc, &Move::inverse_moves(&moves[1..]), &Goal::solved()).unwrap_err();
+	let mut rc_end = RubiksCube::new();
+	rc_end.make_move(Move::Right);
+	assert_eq!(rc_end, e.end);
+	assert_eq!(12, e.mismatches.len());
+	assert!(e.mismatches.windows(2).all(|pair| pair[0].index < pair[1].index));
+	assert!(e.to_string().starts_with("12 stickers differ from goal: 2 is R instead of Y, 5 is R instead of Y"));
+
+	// `R` keeps the left face solved, `F` doesn't
+	let left: Vec<(usize, Color)> = [9, 10, 11, 21, 22, 23, 33, 34, 35].map(|index| (index, Color::B)).to_vec();
+	assert_eq!(Ok(()), verify(&RubiksCube::new(), &[Move::Right], &Goal::Stickers(left.clone())));
+	assert!(verify(&RubiksCube::new(), &[Move::Front], &Goal::Stickers(left)).is_err());
+}